@@ -1,59 +1,276 @@
 use std::ffi::CString;
 
-use proc_macro2::{TokenStream, Literal};
-use quote::{quote};
-use syn::{Data, DeriveInput, Fields, Type, TypePath, parse2};
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use syn::{
+    parse2, Attribute, Data, DataEnum, DeriveInput, Expr, ExprLit, ExprRange, ExprUnary, Field, Fields, FieldsNamed,
+    Lit, RangeLimits, Type, UnOp,
+};
 
 pub fn impl_data_bind(input: TokenStream) -> TokenStream {
-    let input: DeriveInput = parse2(input).expect("Unable to parse struct");
-    let struct_ident = input.ident;
-    let struct_literal = Literal::c_string(&CString::new(struct_ident.to_string()).unwrap());
+    expand_data_bind(input).unwrap_or_else(|err| err.to_compile_error())
+}
+
+/// The errors are reported at the position of the unsupported item.
+fn expand_data_bind(input: TokenStream) -> syn::Result<TokenStream> {
+    let input: DeriveInput = parse2(input)?;
+    let ident = input.ident;
+    let literal = Literal::c_string(&CString::new(ident.to_string()).unwrap());
+    let impl_traits = type_attributes(&input.attrs)?;
 
-    let fields = match input.data {
+    let (register, traits) = match input.data {
         Data::Struct(data) => match data.fields {
-            Fields::Named(fields) => fields.named,
-            _ => panic!("Named fields only"),
+            Fields::Named(fields) => {
+                let traits = if impl_traits {
+                    impl_struct_traits(&ident)
+                } else {
+                    quote! {}
+                };
+                (impl_struct(&ident, &literal, &fields), traits)
+            }
+            _ => return Err(syn::Error::new_spanned(&ident, "DataBind structs need named fields")),
         },
-        _ => panic!("Structs only"),
+        Data::Enum(data) => (impl_enum(&ident, &literal, &data)?, quote! {}),
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "DataBind can be derived for structs and enums only",
+            ))
+        }
     };
 
-    let field_data_bind = fields.iter().map(|f| {
-        let Some(field_ident) = f.ident.as_ref() else {return quote! {}};
-        let Type::Path(field_type_path) = &f.ty else {return quote! {}};
+    Ok(quote! {
+        unsafe impl nappgui::core::DBindType for #ident {
+            fn dbind_type() -> std::ffi::CString {
+                #literal.to_owned()
+            }
 
+            fn dbind_register_type() -> Result<(), nappgui::error::NappguiError> {
+                if nappgui::core::dbind_registered(#literal) {
+                    return Ok(());
+                }
+                <Self as nappgui::core::DataBind>::dbind_register()
+            }
+        }
+
+        unsafe impl nappgui::core::DataBind for #ident {
+            const TYPE: &'static std::ffi::CStr = #literal;
+
+            fn dbind_register() -> Result<(), nappgui::error::NappguiError> {
+                #register
+            }
+        }
+
+        #traits
+    })
+}
+
+/// The types of the fields are registered before the struct, so nested structs, enums and
+/// arrays don't need to be registered by hand.
+fn impl_struct(ident: &Ident, literal: &Literal, fields: &FieldsNamed) -> TokenStream {
+    let field_data_bind = fields.named.iter().map(|f| {
+        let field_ident = f.ident.as_ref().unwrap();
+        let field_type = &f.ty;
         let field_ident_literal = Literal::c_string(&CString::new(field_ident.to_string()).unwrap());
-        let Some(field_type_literal) = nappgui_type(field_type_path) else {return quote! {}};
-        
+        let field_attributes =
+            field_attributes(f, &field_ident_literal).unwrap_or_else(|err| vec![err.to_compile_error()]);
+
         quote! {
+            <#field_type as nappgui::core::DBindType>::dbind_register_type()?;
             dbind.add_field(
                 #field_ident_literal,
-                #field_type_literal,
-                std::mem::offset_of!(#struct_ident, #field_ident) as _,
-                std::mem::size_of::<#field_type_path>() as _,
+                &<#field_type as nappgui::core::DBindType>::dbind_type(),
+                std::mem::offset_of!(#ident, #field_ident) as _,
+                std::mem::size_of::<#field_type>() as _,
             );
+            #(#field_attributes)*
         }
     });
 
     quote! {
-        impl #struct_ident {
-            /// Register the struct to global dbind so that you can bind it to layout.
-            pub fn dbind_register() -> Result<(), nappgui::error::NappguiError> {
-                let dbind = DBindStruct::new(#struct_literal, size_of::<#struct_ident>() as _);
-                #(#field_data_bind)*
-                nappgui::core::dbind_register(dbind)
+        let dbind = nappgui::core::DBindStruct::new(#literal, size_of::<#ident>() as _);
+        #(#field_data_bind)*
+        nappgui::core::dbind_register(dbind)
+    }
+}
+
+/// Parses the `#[dbind(no_traits)]` attribute of the type. Returns whether the traits are
+/// implemented.
+fn type_attributes(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut traits = true;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("dbind")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("no_traits") {
+                traits = false;
+                Ok(())
+            } else {
+                Err(meta.error("unknown dbind attribute"))
+            }
+        })?;
+    }
+    Ok(traits)
+}
+
+/// The structs are copied and compared through DBind, so strings, arrays and nested objects
+/// are handled like in NAppGUI. DBind compares all the fields in order, which is a total
+/// order. Enums can derive these traits as usual.
+fn impl_struct_traits(ident: &Ident) -> TokenStream {
+    quote! {
+        impl Clone for #ident {
+            fn clone(&self) -> Self {
+                nappgui::core::DataBind::dbind_copy(self)
             }
         }
+
+        impl PartialEq for #ident {
+            fn eq(&self, other: &Self) -> bool {
+                nappgui::core::DataBind::dbind_equ(self, other)
+            }
+        }
+
+        impl Eq for #ident {}
+
+        impl PartialOrd for #ident {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for #ident {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                nappgui::core::DataBind::dbind_cmp(self, other)
+            }
+        }
+    }
+}
+
+/// Parses the `#[dbind(range = 0..=100, precision = 0.01, increment = 0.5, suffix = "kg", default = 10)]`
+/// attributes of a field.
+/// The errors are reported at the position of the attribute.
+fn field_attributes(field: &Field, name: &Literal) -> syn::Result<Vec<TokenStream>> {
+    let ty = &field.ty;
+    let mut attributes = Vec::new();
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("dbind")) {
+        attr.parse_nested_meta(|meta| {
+            let key = meta.path.get_ident().map(|ident| ident.to_string()).unwrap_or_default();
+            let value: Expr = meta.value()?.parse()?;
+            let attribute = match key.as_str() {
+                "range" => {
+                    let Expr::Range(ExprRange {
+                        start: Some(start),
+                        end: Some(end),
+                        limits: RangeLimits::Closed(_),
+                        ..
+                    }) = &value
+                    else {
+                        return Err(meta.error("expected a closed range, like `0..=100`"));
+                    };
+                    let (min, max) = (typed_value(start, ty)?, typed_value(end, ty)?);
+                    quote! { dbind.set_range::<#ty>(#name, #min, #max); }
+                }
+                "precision" => {
+                    let value = typed_value(&value, ty)?;
+                    quote! { dbind.set_precision::<#ty>(#name, #value); }
+                }
+                "increment" => {
+                    let value = typed_value(&value, ty)?;
+                    quote! { dbind.set_increment::<#ty>(#name, #value); }
+                }
+                "suffix" => {
+                    let suffix = c_string(&value)?.ok_or_else(|| meta.error("expected a string literal"))?;
+                    quote! { dbind.set_suffix(#name, #suffix); }
+                }
+                "default" => match c_string(&value)? {
+                    Some(text) => quote! { dbind.set_default_text(#name, #text); },
+                    None => {
+                        let value = typed_value(&value, ty)?;
+                        quote! { dbind.set_default::<#ty>(#name, #value); }
+                    }
+                },
+                _ => return Err(meta.error("unknown dbind attribute")),
+            };
+            attributes.push(attribute);
+            Ok(())
+        })?;
     }
+    Ok(attributes)
 }
 
-fn nappgui_type(path: &TypePath) -> Option<Literal> {
-    let last = path.path.segments.last()?;
-    let ident = &last.ident;
-    match ident {
-        _ if ident == "NappguiString"=> Some(Literal::c_string(c"String")),
-        _ if ident == "NappguiInt"  || ident == "i32" => Some(Literal::c_string(c"int32_t")),
-        _ if ident == "NappguiReal"|| ident == "f32" => Some(Literal::c_string(c"float32_t")),
-        _ if ident == "NappguiBoolean"|| ident == "bool" => Some(Literal::c_string(c"bool_t")),
+/// Numeric literals are cast to the field type, so `range = 0..=100` is valid for a `f32` field.
+/// Float literals are rejected for integer fields, instead of being truncated.
+fn typed_value(expr: &Expr, ty: &Type) -> syn::Result<TokenStream> {
+    let lit = match expr {
+        Expr::Lit(ExprLit { lit, .. }) => Some(lit),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_), expr, ..
+        }) => match expr.as_ref() {
+            Expr::Lit(ExprLit { lit, .. }) => Some(lit),
+            _ => None,
+        },
         _ => None,
+    };
+    match lit {
+        Some(Lit::Float(_)) if is_integer(ty) => Err(syn::Error::new_spanned(
+            expr,
+            "expected an integer, the field has an integer type",
+        )),
+        Some(Lit::Int(_) | Lit::Float(_)) => Ok(quote! { (#expr) as #ty }),
+        _ => Ok(quote! { #expr }),
+    }
+}
+
+fn is_integer(ty: &Type) -> bool {
+    const INTEGERS: &[&str] = &["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"];
+    match ty {
+        Type::Path(path) => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| INTEGERS.iter().any(|integer| ident == integer)),
+        _ => false,
+    }
+}
+
+/// A string literal as a C string. None if the expression is not a string literal.
+fn c_string(expr: &Expr) -> syn::Result<Option<Literal>> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(text), ..
+        }) => match CString::new(text.value()) {
+            Ok(text) => Ok(Some(Literal::c_string(&text))),
+            Err(_) => Err(syn::Error::new_spanned(text, "the text can't contain a NUL character")),
+        },
+        _ => Ok(None),
     }
 }
+
+/// Only C-like enums can be registered. NAppGUI stores enums as `int`, so they need
+/// `#[repr(i32)]`.
+fn impl_enum(ident: &Ident, literal: &Literal, data: &DataEnum) -> syn::Result<TokenStream> {
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new_spanned(
+                    &variant.fields,
+                    "DataBind enums can only have unit variants",
+                ));
+            }
+            let variant_ident = &variant.ident;
+            let variant_literal = Literal::c_string(&CString::new(variant_ident.to_string()).unwrap());
+            Ok(quote! {
+                dbind.add_variant(#variant_literal, #ident::#variant_ident as i32, #variant_literal);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        const _: () = assert!(
+            size_of::<#ident>() == size_of::<i32>(),
+            "DataBind enums must be #[repr(i32)]"
+        );
+        let dbind = nappgui::core::DBindEnum::new(#literal);
+        #(#variants)*
+        nappgui::core::dbind_register(dbind)
+    })
+}
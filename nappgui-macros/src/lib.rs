@@ -11,7 +11,12 @@ pub fn include_resource(input: TokenStream) -> TokenStream {
     resource::include_resource(input)
 }
 
-#[proc_macro_derive(DataBind)]
+/// Registers a struct or a C-like enum in DBind.
+///
+/// # Remarks
+/// Structs also get `Clone`, `PartialEq`, `Eq`, `PartialOrd` and `Ord` implemented with DBind, so
+/// they can't derive these traits. Use `#[dbind(no_traits)]` on the struct to implement them by hand.
+#[proc_macro_derive(DataBind, attributes(dbind))]
 pub fn data_bind(input: TokenStream) -> TokenStream {
     let input2 = proc_macro2::TokenStream::from(input);
     let output2 = dbind::impl_data_bind(input2);
@@ -238,6 +238,18 @@ fn generate_code(resource: &Resource) -> String {
         }
     }
 
+    // name -> sid table, used by ui::load_with_resources to find `@HELLO_MSG`
+    let names: Vec<String> = resource
+        .uids_ordered
+        .iter()
+        .map(|uid| format!("(\"{}\", \"{}\")", uid, resource.get_sid(uid).unwrap()))
+        .collect();
+    code.push(format!(
+        "pub static {}_RESOURCES: &[(&str, &str)] = &[{}];",
+        resource.rid.to_uppercase(),
+        names.join(", ")
+    ));
+
     code.push(format!(
         "pub unsafe extern \"C\" fn {}_respack(locale: *const std::ffi::c_char) -> nappgui::core::ResPackPtr {{",
         resource.rid
@@ -1,33 +1,93 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use roxmltree::Node;
-use syn::{parse2, Ident, LitFloat, LitInt, LitStr};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse2, Ident, LitStr, Token,
+};
+
+use validate::Source;
+
+mod validate;
 
 pub fn process_ui_macro(input: TokenStream) -> TokenStream {
-    let xml_literal = match parse2::<LitStr>(input) {
-        Ok(lit) => lit.value(),
+    let (source, file) = match parse2::<UiInput>(input) {
+        Ok(UiInput::Inline(lit)) => (Source::new(lit), None),
+        Ok(UiInput::File(lit)) => match resolve_path(&lit).and_then(|path| Source::from_file(lit, &path)) {
+            Ok(source) => {
+                let file = source.path().map(Path::to_path_buf);
+                (source, file)
+            }
+            Err(e) => return e.to_compile_error(),
+        },
         Err(e) => return e.to_compile_error(),
     };
 
-    let doc = match roxmltree::Document::parse(&xml_literal) {
+    let doc = match roxmltree::Document::parse(source.xml()) {
         Ok(parsed_doc) => parsed_doc,
-        Err(err) => {
-            // Fallback: provide a compile error highlighting the issue
-            return syn::Error::new(
-                Span::call_site(),
-                format!(
-                    "Implicit XML Syntax Error: {}. Raw content processed: '{}'",
-                    err, xml_literal
-                ),
-            )
-            .to_compile_error();
-        }
+        Err(err) => return source.xml_error(&err).to_compile_error(),
     };
 
-    let generator = Generator::from_xml_doc(&doc);
-    generator.generate()
+    let mut generator = Generator::from_xml_doc(&doc);
+    if let Err(err) = generator.validate(&source) {
+        return err.to_compile_error();
+    }
+
+    // The XML file is included in the generated code, so the crate is rebuilt when it changes.
+    let track_file = file.map(|file| {
+        if let Some(dir) = file.parent() {
+            generator.resolve_images(dir);
+        }
+        let file = LitStr::new(&file.to_string_lossy(), Span::call_site());
+        quote! { const _: &[u8] = include_bytes!(#file); }
+    });
+    let code = generator.generate();
+    quote! {
+        #track_file
+        #code
+    }
+}
+
+/// Input of the macro: an XML literal, or `file = "path"` to read the XML from a file.
+enum UiInput {
+    Inline(LitStr),
+    File(LitStr),
+}
+
+impl Parse for UiInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(Self::Inline(input.parse()?));
+        }
+        let key: Ident = input.parse()?;
+        if key != "file" {
+            return Err(syn::Error::new(
+                key.span(),
+                "expected an XML literal or `file = \"path\"`",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        Ok(Self::File(input.parse()?))
+    }
+}
+
+/// Resolve a path relative to the file where the macro is called, like `include_resource!`.
+fn resolve_path(lit: &LitStr) -> syn::Result<PathBuf> {
+    let path = PathBuf::from(lit.value());
+    if path.is_absolute() {
+        return Ok(path);
+    }
+    let local_file = proc_macro::Span::call_site()
+        .local_file()
+        .ok_or_else(|| syn::Error::new(lit.span(), "unable to get the path of the calling file"))?;
+    let local_file_parent = local_file.parent().unwrap_or(Path::new(""));
+    Ok(local_file_parent.join(path))
 }
 
 struct Generator {
@@ -81,7 +141,8 @@ impl Generator {
             } else {
                 generator.root = Some(node_id);
             }
-            for child in node.children() {
+            // The children are pushed in reverse order, so they are added in document order.
+            for child in node.children().rev() {
                 if child.is_element() {
                     nodes_stack.push((child, Some(node_id)));
                 }
@@ -116,7 +177,7 @@ impl Generator {
                 if bool::from_str(node.attr_or("multiline|multi-line", "false")) {
                     quote! { Edit::new_multiline() }
                 } else {
-                    quote! { Edsit::new() }
+                    quote! { Edit::new() }
                 }
             }
             FieldType::ImageView => quote! { ImageView::new() },
@@ -150,6 +211,15 @@ impl Generator {
                 }
             }
             FieldType::TableView => quote! { TableView::new() },
+            FieldType::Tabs => {
+                let position = match node.attr_or("position|pos", "top") {
+                    "bottom" => Ident::new("Bottom", Span::call_site()),
+                    "left" => Ident::new("Left", Span::call_site()),
+                    "right" => Ident::new("Right", Span::call_site()),
+                    _ => Ident::new("Top", Span::call_site()),
+                };
+                quote! { Tabs::new(GuiPosition::#position) }
+            }
             FieldType::TextView => quote! { TextView::new() },
             FieldType::UpDown => quote! { UpDown::new() },
             FieldType::View => quote! { View::new() },
@@ -162,12 +232,20 @@ impl Generator {
                 }
             }
             FieldType::Layout => {
-                let columns = LitInt::new(node.attr_or("cols|columns", "1"), Span::call_site());
-                let rows = LitInt::new(node.attr_or("rows", "1"), Span::call_site());
+                let columns = node.uint_attr("cols|columns").unwrap_or(1);
+                let rows = node.uint_attr("rows").unwrap_or(1);
                 quote! { Layout::new(#columns, #rows) }
             }
-            FieldType::Window => quote! { Window::new(WindowFlags::default()) },
-            FieldType::Cell => return None,
+            FieldType::Window => quote! { Window::new() },
+            FieldType::Menu => quote! { Menu::new() },
+            FieldType::MenuItem => {
+                if bool::from_str(node.attr_or("separator", "false")) {
+                    quote! { MenuItem::new_separator() }
+                } else {
+                    quote! { MenuItem::new() }
+                }
+            }
+            FieldType::Cell | FieldType::Item | FieldType::Column | FieldType::Row => return None,
             FieldType::Custom(_) => {
                 let custom = node.type_ident()?;
                 quote! { #custom::new() }
@@ -180,120 +258,230 @@ impl Generator {
 
     fn apply_attr(&self, id: usize) -> Option<TokenStream> {
         let node = self.node(id);
+        let ty = node.base_type();
         let name = node.name_ident();
-        match node.ty {
-            FieldType::Button => {
-                let text_setter = node.attr("text").map(|text| {
-                    let text = LitStr::new(text, Span::call_site());
-                    quote! { obj.#name.set_text(#text); }
-                });
-                let width_setter = node.attr("width").map(|width| {
-                    let width = LitFloat::new(width, Span::call_site());
-                    quote! { obj.#name.set_width(#width); }
-                });
-                let set_text_alt_setter = node.attr("set-text-alt").map(|set_text_alt| {
-                    let set_text_alt = LitStr::new(set_text_alt, Span::call_site());
-                    quote! { obj.#name.set_text_alt(#set_text_alt); }
-                });
-                let font_setter: Option<TokenStream> = {
-                    let font_family = node
-                        .attr("font-family")
-                        .map(|font_family| LitStr::new(font_family, Span::call_site()));
-                    let font_size = node
-                        .attr("font-size")
-                        .map(|font_size| LitFloat::new(font_size, Span::call_site()))?;
-                    let font_style = node.attr_or("font-style", "normal");
-                    let styles = font_style.split("|");
-                    let mut font_style = vec![];
-                    for style in styles {
-                        match style {
-                            "normal" => font_style.push(Ident::new("Normal", Span::call_site())),
-                            "bold" => font_style.push(Ident::new("Bold", Span::call_site())),
-                            "italic" => font_style.push(Ident::new("Italic", Span::call_site())),
-                            "strike_out" => font_style.push(Ident::new("StrikeOut", Span::call_site())),
-                            "underlined" => font_style.push(Ident::new("Underlined", Span::call_site())),
-                            "subscript" => font_style.push(Ident::new("Subscript", Span::call_site())),
-                            "superscript" => font_style.push(Ident::new("Superscript", Span::call_site())),
-                            "points" => font_style.push(Ident::new("Points", Span::call_site())),
-                            "cell" => font_style.push(Ident::new("Cell", Span::call_site())),
-                            _ => (),
-                        }
-                    }
+        match ty {
+            FieldType::Cell => return self.apply_cell_attr(id),
+            FieldType::Item | FieldType::Column | FieldType::Row | FieldType::Custom(_) => return None,
+            _ => (),
+        }
 
-                    if let Some(font_family) = font_family {
-                        Some(quote! {
-                            let font = Font::new(#font_family, #font_size, #(FontStyle::#font_style)|*);
-                            obj.#name.set_font(&font);
-                        })
-                    } else {
-                        Some(quote! {
-                            let font = Font::system(#font_size, #(FontStyle::#font_style)|*);
-                            obj.#name.set_font(&font);
-                        })
-                    }
+        // TableView setters take `&mut self`, so they are called on a copy of the object.
+        let target = if ty == FieldType::TableView {
+            quote! { #name }
+        } else {
+            quote! { obj.#name }
+        };
+        let children = match ty {
+            FieldType::ListBox | FieldType::PopUp | FieldType::Combo | FieldType::Tabs => self.apply_items(id, &target),
+            FieldType::TableView => self.apply_table_columns(id, &target),
+            FieldType::Layout => self.apply_layout_children(id, &target),
+            FieldType::Menu => self.apply_menu_items(id, &target),
+            _ => None,
+        };
+        let setters = setter_calls(node, &target, &quote! {}, ty.setters());
+        let font_setter = match ty {
+            FieldType::Button
+            | FieldType::Label
+            | FieldType::Edit
+            | FieldType::Combo
+            | FieldType::ListBox
+            | FieldType::TableView => node.font_setter(&target),
+            _ => None,
+        };
+        let extra = match ty {
+            FieldType::Layout => node.attr("group").and_then(|group| {
+                let group = AttrKind::Text.args(group)?;
+                Some(quote! { #target.set_group(true, #group); })
+            }),
+            FieldType::SplitView => node.attr("position|pos").and_then(|position| {
+                let position = AttrKind::Float.args(position)?;
+                let mode = match node.attr_or("split-mode|mode", "normal") {
+                    "fixed0" => Ident::new("Fixed0", Span::call_site()),
+                    "fixed1" => Ident::new("Fixed1", Span::call_site()),
+                    _ => Ident::new("Normal", Span::call_site()),
                 };
-                Some(quote! {
-                    #text_setter
-                    #width_setter
-                    #set_text_alt_setter
-                    #font_setter
-                })
-            }
-            FieldType::Label => {
-                let text_setter = node.attr("text").map(|text| {
-                    let text = LitStr::new(text, Span::call_site());
-                    quote! { obj.#name.set_text(#text); }
-                });
-                Some(quote! {
-                    #text_setter
-                })
+                Some(quote! { #target.set_position(SplitMode::#mode, #position); })
+            }),
+            FieldType::Window => node.attr("default-button").map(|button| {
+                let button = Ident::new(button, Span::call_site());
+                quote! { #target.set_default_button(obj.#button); }
+            }),
+            FieldType::Menu => {
+                // The menu is the menu bar of a window, or the submenu of an item.
+                let parent = self.node(node.parent?);
+                let parent_name = parent.name_ident();
+                match parent.base_type() {
+                    FieldType::Window => Some(quote! { obj.#parent_name.set_menubar(obj.#name); }),
+                    FieldType::MenuItem => Some(quote! { obj.#parent_name.set_submenu(obj.#name); }),
+                    _ => None,
+                }
             }
             FieldType::Panel => {
-                let panel_name = node.name_ident();
+                // The panel is the main panel of the window it is declared in.
                 let window = self.node(node.parent?);
-                let window_name = window.name_ident();
-                Some(quote! {
-                    obj.#window_name.set_panel(obj.#panel_name);
-                })
-            }
-            FieldType::Window => {
-                let title_setter = node.attr("title").map(|title| {
-                    let title = LitStr::new(title, Span::call_site());
-                    quote! { obj.#name.set_title(#title); }
-                });
-                let client_size_setter = node.attr("client-size|size").and_then(|client_size| {
-                    let client_size = client_size.split(',').collect::<Vec<_>>();
-                    if client_size.len() != 2 {
-                        return None;
-                    }
-                    let width = LitFloat::new(client_size[0], Span::call_site());
-                    let height = LitFloat::new(client_size[1], Span::call_site());
-                    Some(quote! { obj.#name.set_client_size(#width, #height); })
-                });
-                let origin_setter = node.attr("origin").and_then(|origin| {
-                    let origin = origin.split(',').collect::<Vec<_>>();
-                    if origin.len() != 2 {
-                        return None;
-                    }
-                    let x = LitFloat::new(origin[0], Span::call_site());
-                    let y = LitFloat::new(origin[1], Span::call_site());
-                    Some(quote! { obj.#name.set_origin(#x, #y); })
-                });
-                Some(quote! {
-                    #title_setter
-                    #client_size_setter
-                    #origin_setter
+                (window.base_type() == FieldType::Window).then(|| {
+                    let window_name = window.name_ident();
+                    quote! { obj.#window_name.set_panel(obj.#name); }
                 })
             }
             _ => None,
+        };
+
+        if children.is_none() && setters.is_empty() && font_setter.is_none() && extra.is_none() {
+            return None;
+        }
+        let copy = (ty == FieldType::TableView).then(|| {
+            quote! {
+                #[allow(unused_mut)]
+                let mut #name = obj.#name;
+            }
+        });
+        Some(quote! {
+            {
+                #copy
+                #children
+                #(#setters)*
+                #font_setter
+                #extra
+            }
+        })
+    }
+
+    /// Adds the `<item>` children of a list-like control, in the order they are declared.
+    fn apply_items(&self, id: usize, target: &TokenStream) -> Option<TokenStream> {
+        let node = self.node(id);
+        let ty = node.base_type();
+        let items = self
+            .children_of(id, FieldType::Item)
+            .enumerate()
+            .map(|(index, item)| {
+                let index = index as u32;
+                let text = AttrKind::Text
+                    .args(item.attr("text").or(item.text.as_deref()).unwrap_or(""))
+                    .unwrap_or_else(|| quote! { "" });
+                let image = match item.attr("image").and_then(|image| AttrKind::Image.args(image)) {
+                    Some(image) => quote! { Some(#image) },
+                    None => quote! { None },
+                };
+                let selected = item
+                    .attr("selected")
+                    .and_then(parse_bool)
+                    .unwrap_or(false)
+                    .then(|| match ty {
+                        FieldType::ListBox => quote! { #target.select(#index, true); },
+                        _ => quote! { #target.set_selected(#index); },
+                    });
+                let list_setters = if ty == FieldType::ListBox {
+                    setter_calls(item, target, &quote! { #index, }, LIST_ITEM_SETTERS)
+                } else {
+                    Vec::new()
+                };
+                quote! {
+                    #target.add_element(#text, #image);
+                    #(#list_setters)*
+                    #selected
+                }
+            })
+            .collect::<Vec<_>>();
+        (!items.is_empty()).then(|| quote! { #(#items)* })
+    }
+
+    /// Adds the `<menuitem>` children of a menu, in the order they are declared.
+    fn apply_menu_items(&self, id: usize, target: &TokenStream) -> Option<TokenStream> {
+        let items = self
+            .children_of(id, FieldType::MenuItem)
+            .map(|item| {
+                let item = item.name_ident();
+                quote! { #target.add_item(obj.#item); }
+            })
+            .collect::<Vec<_>>();
+        (!items.is_empty()).then(|| quote! { #(#items)* })
+    }
+
+    /// Adds the `<column>` children of a table view, in the order they are declared.
+    fn apply_table_columns(&self, id: usize, target: &TokenStream) -> Option<TokenStream> {
+        let columns = self
+            .children_of(id, FieldType::Column)
+            .map(|column| {
+                let setters = setter_calls(column, target, &quote! { column_id, }, TABLE_COLUMN_SETTERS);
+                quote! {
+                    let column_id = #target.add_column();
+                    #(#setters)*
+                }
+            })
+            .collect::<Vec<_>>();
+        (!columns.is_empty()).then(|| quote! { #(#columns)* })
+    }
+
+    /// Applies the `<column>` and `<row>` children of a layout. Both need an `index` attribute.
+    ///
+    /// # Remarks
+    /// The columns (rows) with `expand="true"` share the extra space. The `weight` is the
+    /// proportion of space of each one, the last one takes the rest and can't have a `weight`.
+    fn apply_layout_children(&self, id: usize, target: &TokenStream) -> Option<TokenStream> {
+        let mut children = Vec::new();
+        for (ty, index_attr, setters, expand_setter) in [
+            (
+                FieldType::Column,
+                "index|column|col",
+                LAYOUT_COLUMN_SETTERS,
+                "set_horizontal_expand",
+            ),
+            (FieldType::Row, "index|row", LAYOUT_ROW_SETTERS, "set_vertical_expand"),
+        ] {
+            let mut expand = Vec::new();
+            for child in self.children_of(id, ty) {
+                let Some(index) = child
+                    .attr(index_attr)
+                    .and_then(|index| index.trim().parse::<u32>().ok())
+                else {
+                    continue;
+                };
+                children.extend(setter_calls(child, target, &quote! { #index, }, setters));
+                if child.attr("expand").and_then(parse_bool).unwrap_or(false) {
+                    let weight = child
+                        .attr("weight")
+                        .and_then(|weight| weight.trim().parse::<f32>().ok());
+                    expand.push((index, weight));
+                }
+            }
+            if let Some((_, first)) = expand.split_last() {
+                let default_weight = 1.0 / expand.len() as f32;
+                let indices = expand.iter().map(|(index, _)| index);
+                let weights = first.iter().map(|(_, weight)| weight.unwrap_or(default_weight));
+                let expand_setter = Ident::new(expand_setter, Span::call_site());
+                children.push(quote! { #target.#expand_setter(&[#(#indices),*], &[#(#weights),*]); });
+            }
         }
+        (!children.is_empty()).then(|| quote! { #(#children)* })
+    }
+
+    /// Applies the attributes of a `<cell>` to the cell of the parent layout.
+    fn apply_cell_attr(&self, id: usize) -> Option<TokenStream> {
+        let node = self.node(id);
+        let layout = self.node(node.parent?);
+        let layout_name = layout.name_ident();
+        let col = node.uint_attr("column|col")?;
+        let row = node.uint_attr("row")?;
+        let setters = setter_calls(
+            node,
+            &quote! { obj.#layout_name },
+            &quote! { #col, #row, },
+            CELL_SETTERS,
+        );
+        (!setters.is_empty()).then(|| quote! { #(#setters)* })
     }
 
     fn apply_layout(&self, id: usize) -> Option<TokenStream> {
         let node = self.node(id);
         match node.ty {
             FieldType::Layout => {
+                // A layout declared in another layout is a sublayout, placed with a `<cell>`.
                 let panel = self.node(node.parent?);
+                if panel.base_type() != FieldType::Panel {
+                    return None;
+                }
                 let panel_name = panel.name_ident();
                 let layout_name = node.name_ident();
                 Some(quote! {
@@ -303,8 +491,8 @@ impl Generator {
             FieldType::Cell => {
                 let layout = self.node(node.parent?);
                 let layout_name = layout.name_ident();
-                let col = LitInt::new(node.attr("column|col")?, Span::call_site());
-                let row = LitInt::new(node.attr("row")?, Span::call_site());
+                let col = node.uint_attr("column|col")?;
+                let row = node.uint_attr("row")?;
                 let control_name = Ident::new(node.attr("for|control")?, Span::call_site());
                 Some(quote! {
                     obj.#layout_name.set_control(#col, #row, obj.#control_name);
@@ -314,56 +502,63 @@ impl Generator {
         }
     }
 
+    /// Generate a `setter_<handler>` method for each event handler declared as an attribute, like
+    /// `on-click="button_click"`. The method sets the handler of the control, with the same
+    /// callback type as the `set_on_*_handler` method of the control.
     fn generate_setter(&self, id: usize) -> Option<TokenStream> {
         let node = self.node(id);
         let name = node.name_ident();
-        match node.ty {
-            FieldType::Button => {
-                let on_click_setter = node.attr("on-click").map(|on_click| {
-                    let on_click = Ident::new(&format!("setter_{}", on_click), Span::call_site());
-                    quote! {
-                        pub fn #on_click<F>(&self, callback: F)
-                        where
-                            F: Fn(&ButtonEvent) + 'static
-                        {
-                            self.#name.set_on_click_handler(callback);
-                        }
-                    }
-                });
+        let ty = node.base_type();
+        let mut setters = ty
+            .events()
+            .iter()
+            .filter_map(|(attr, handler, callback)| {
+                let setter = Ident::new(&format!("setter_{}", node.attr(attr)?), Span::call_site());
+                let handler = Ident::new(handler, Span::call_site());
+                let callback: TokenStream = callback.parse().unwrap();
                 Some(quote! {
-                    #on_click_setter
-                })
-            }
-            FieldType::Window => {
-                let on_close_setter = node.attr("on-close").map(|on_close| {
-                    let on_close = Ident::new(&format!("setter_{}", on_close), Span::call_site());
-                    quote! {
-                        pub fn #on_close<F>(&self, callback: F)
-                        where
-                            F: Fn(&WindowCloseEvent) -> bool + 'static,
-                        {
-                            self.#name.set_on_close_handler(callback);
-                        }
+                    pub fn #setter<F>(&self, callback: F)
+                    where
+                        F: #callback + 'static,
+                    {
+                        self.#name.#handler(callback);
                     }
-                });
-                Some(quote! {
-                    #on_close_setter
                 })
-            }
-            FieldType::TextView => {
-                let text_setter = node.attr("write").map(|text| {
-                    let text_setter = Ident::new(&format!("setter_{}", text), Span::call_site());
-                    quote! {
-                        pub fn #text_setter(&self, text: &str) {
-                            self.#name.write(text)
-                        }
+            })
+            .collect::<Vec<_>>();
+        if ty == FieldType::TextView {
+            setters.extend(node.attr("write").map(|text| {
+                let text_setter = Ident::new(&format!("setter_{}", text), Span::call_site());
+                quote! {
+                    pub fn #text_setter(&self, text: &str) {
+                        self.#name.write(text)
                     }
-                });
-                Some(quote! {
-                    #text_setter
-                })
+                }
+            }));
+        }
+        (!setters.is_empty()).then(|| quote! { #(#setters)* })
+    }
+
+    /// Make the paths of the images absolute, resolving them relative to a directory. The paths in
+    /// an XML file are relative to the file, not to the source that includes it.
+    fn resolve_images(&mut self, dir: &Path) {
+        for node in self.nodes.values_mut() {
+            let mut images: Vec<&str> = node
+                .base_type()
+                .setters()
+                .iter()
+                .filter(|(_, _, kind)| matches!(kind, AttrKind::Image))
+                .flat_map(|(attr, _, _)| attr.split('|'))
+                .collect();
+            if node.ty == FieldType::Item {
+                images.push("image");
+            }
+            for image in images {
+                // Images from resources, like `@ICON_PNG`, are not files.
+                if let Some(path) = node.attrs.get_mut(image).filter(|path| !path.starts_with('@')) {
+                    *path = dir.join(path.trim()).to_string_lossy().into_owned();
+                }
             }
-            _ => None,
         }
     }
 
@@ -379,7 +574,7 @@ impl Generator {
                 #(#nodes)*
             }
 
-            use #mod_name::*;
+            use self::#mod_name::*;
         }
     }
 
@@ -404,6 +599,8 @@ impl Generator {
         let apply_layouts = child_nodes.iter().filter_map(|node| self.apply_layout(*node));
         let apply_attrs = child_nodes.iter().filter_map(|node| self.apply_attr(*node));
         let define_setters = child_nodes.iter().filter_map(|node| self.generate_setter(*node));
+        let apply_inner_attrs = self.apply_attr(id);
+        let define_inner_setters = self.generate_setter(id);
         quote! {
             #[derive(Debug, Clone, Copy)]
             pub struct #struct_ident {
@@ -419,10 +616,12 @@ impl Generator {
                     };
                     #(#apply_layouts)*
                     #(#apply_attrs)*
+                    #apply_inner_attrs
                     obj
                 }
 
                 #(#define_setters)*
+                #define_inner_setters
             }
 
             impl std::ops::Deref for #struct_ident {
@@ -440,6 +639,15 @@ impl Generator {
         }
     }
 
+    /// Get the children of a node with the given type, in document order.
+    fn children_of(&self, id: usize, ty: FieldType) -> impl Iterator<Item = &GeneratorNode> {
+        self.node(id)
+            .children
+            .iter()
+            .map(|child| self.node(*child))
+            .filter(move |child| child.ty == ty)
+    }
+
     /// Get the node from the generator. If not found, panic.
     fn node(&self, id: usize) -> &GeneratorNode {
         self.nodes.get(&id).unwrap()
@@ -477,6 +685,14 @@ struct GeneratorNode {
     ty: FieldType,
     /// Attributes of the node, used to apply properties to the object.
     attrs: HashMap<String, String>,
+    /// Text content of the node, used by `<item>` elements.
+    text: Option<String>,
+    /// Tag name of the node, as written in the XML.
+    tag: String,
+    /// Position of the tag name in the XML, used to report errors.
+    range: Range<usize>,
+    /// Positions of the name and the value of each attribute in the XML, in document order.
+    attr_ranges: Vec<(String, Range<usize>, Range<usize>)>,
     /// The unique ident of the parent node, used to build the hierarchy of objects.
     parent: Option<usize>,
     /// The unique ident of the children nodes, used to build the hierarchy of objects.
@@ -490,11 +706,27 @@ impl GeneratorNode {
             .attributes()
             .map(|attr| (attr.name().to_string(), attr.value().to_string()))
             .collect();
-        let ty = FieldType::from_str(node.tag_name().name());
+        let attr_ranges = node
+            .attributes()
+            .map(|attr| (attr.name().to_string(), attr.range_qname(), attr.range_value()))
+            .collect();
+        let tag = node.tag_name().name().to_string();
+        // The tag name starts after the `<`.
+        let start = node.range().start + 1;
+        let range = start..start + tag.len();
+        let ty = FieldType::from_str(&tag);
+        let text = node
+            .text()
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty());
         Self {
             id,
             ty,
             attrs,
+            text,
+            tag,
+            range,
+            attr_ranges,
             parent: None,
             children: Vec::new(),
         }
@@ -523,6 +755,7 @@ impl GeneratorNode {
             FieldType::Slider => Some(Ident::new("Slider", Span::call_site())),
             FieldType::SplitView => Some(Ident::new("SplitView", Span::call_site())),
             FieldType::TableView => Some(Ident::new("TableView", Span::call_site())),
+            FieldType::Tabs => Some(Ident::new("Tabs", Span::call_site())),
             FieldType::TextView => Some(Ident::new("TextView", Span::call_site())),
             FieldType::UpDown => Some(Ident::new("UpDown", Span::call_site())),
             FieldType::View => Some(Ident::new("View", Span::call_site())),
@@ -530,6 +763,8 @@ impl GeneratorNode {
             FieldType::Line => Some(Ident::new("Line", Span::call_site())),
             FieldType::Layout => Some(Ident::new("Layout", Span::call_site())),
             FieldType::Window => Some(Ident::new("Window", Span::call_site())),
+            FieldType::Menu => Some(Ident::new("Menu", Span::call_site())),
+            FieldType::MenuItem => Some(Ident::new("MenuItem", Span::call_site())),
             FieldType::Custom(custom) => Some(Ident::new(custom, Span::call_site())),
             _ => None,
         }
@@ -537,6 +772,15 @@ impl GeneratorNode {
 
     /// Returns the real type of the node.
     /// If the node is a root node, it returns the type specified in the `inherits` attribute.
+    fn base_type(&self) -> FieldType {
+        match (&self.ty, self.attr("inherits|extends")) {
+            (FieldType::Custom(_), Some(base)) => FieldType::from_str(base),
+            (ty, _) => ty.clone(),
+        }
+    }
+
+    /// Returns the value of the first attribute found, or the default value.
+    /// Alternative names of the attribute are separated by `|`.
     fn attr_or(&self, name: &str, default: &'static str) -> &str {
         let names: Vec<&str> = name.split("|").collect();
         for name in names {
@@ -556,6 +800,28 @@ impl GeneratorNode {
         }
         None
     }
+
+    /// Returns the value of an attribute as a `u32` number.
+    fn uint_attr(&self, name: &str) -> Option<u32> {
+        self.attr(name)?.trim().parse().ok()
+    }
+
+    /// Returns the `set_font` call from the `font-family`, `font-size` and `font-style` attributes.
+    fn font_setter(&self, target: &TokenStream) -> Option<TokenStream> {
+        let font_size = AttrKind::Float.args(self.attr("font-size")?)?;
+        let font_style = AttrKind::FontStyle.args(self.attr_or("font-style", "normal"))?;
+        let font = match self.attr("font-family") {
+            Some(font_family) => {
+                let font_family = LitStr::new(font_family, Span::call_site());
+                quote! { Font::new(#font_family, #font_size, #font_style) }
+            }
+            None => quote! { Font::system(#font_size, #font_style) },
+        };
+        Some(quote! {
+            let font = #font;
+            #target.set_font(&font);
+        })
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -572,6 +838,7 @@ enum FieldType {
     Slider,
     SplitView,
     TableView,
+    Tabs,
     TextView,
     UpDown,
     View,
@@ -579,7 +846,12 @@ enum FieldType {
     Line,
     Layout,
     Cell,
+    Item,
+    Column,
+    Row,
     Window,
+    Menu,
+    MenuItem,
     Custom(String),
 }
 
@@ -598,6 +870,7 @@ impl FieldType {
             "slider" => Self::Slider,
             "splitview" | "split-view" | "split_view" => Self::SplitView,
             "tableview" | "table-view" | "table_view" => Self::TableView,
+            "tabs" => Self::Tabs,
             "textview" | "text-view" | "text_view" => Self::TextView,
             "updown" | "up-down" | "up_down" => Self::UpDown,
             "view" => Self::View,
@@ -605,12 +878,622 @@ impl FieldType {
             "line" => Self::Line,
             "layout" => Self::Layout,
             "cell" => Self::Cell,
+            "item" => Self::Item,
+            "column" | "col" => Self::Column,
+            "row" => Self::Row,
             "window" | "win" => Self::Window,
+            "menu" => Self::Menu,
+            "menuitem" | "menu-item" | "menu_item" => Self::MenuItem,
             _ => Self::Custom(s.trim().to_owned()),
         }
     }
 }
 
+/// A setter declared as an attribute: (attribute names, setter method, kind of value).
+/// Alternative names of the attribute are separated by `|`.
+type Setter = (&'static str, &'static str, AttrKind);
+
+impl FieldType {
+    /// The setters that can be declared as attributes of each type of node.
+    fn setters(&self) -> &'static [Setter] {
+        use AttrKind::*;
+        match self {
+            Self::Button => &[
+                ("text", "set_text", Localized("set_text_rid")),
+                ("text-alt|set-text-alt", "set_text_alt", Text),
+                ("width", "set_width", Float),
+                ("image", "set_image", Image),
+                ("image-alt", "set_image_alt", Image),
+                ("image-position", "set_image_position", Position),
+                ("state", "set_state", State),
+                ("tooltip", "set_tooltip", Text),
+                ("hpadding", "set_hpadding", Float),
+                ("vpadding", "set_vpadding", Float),
+            ],
+            Self::Combo => &[
+                ("width", "set_width", Float),
+                ("text", "set_text", Text),
+                ("align", "set_align", Align),
+                ("passmode", "set_passmode", Bool),
+                ("editable", "set_editable", Bool),
+                ("autoselect", "set_autoselect", Bool),
+                ("tooltip", "set_tooltip", Text),
+                ("color", "set_color", Color),
+                ("color-focus", "set_color_focus", Color),
+                ("background-color", "set_background_color", Color),
+                ("background-color-focus", "set_background_color_focus", Color),
+                ("placeholder|placeholder-text", "set_placeholder_text", Text),
+                ("placeholder-color", "set_placeholder_color", Color),
+                ("placeholder-style", "set_placeholder_style", FontStyle),
+                ("list-height", "set_list_height", UInt),
+                ("selected", "set_selected", UInt),
+            ],
+            Self::Edit => &[
+                ("text", "set_text", Text),
+                ("align", "set_align", Align),
+                ("passmode", "set_passmode", Bool),
+                ("editable", "set_editable", Bool),
+                ("autoselect", "set_autoselect", Bool),
+                ("select", "set_select", Ints),
+                ("tooltip", "set_tooltip", Text),
+                ("color", "set_color", Color),
+                ("color-focus", "set_color_focus", Color),
+                ("background-color", "set_background_color", Color),
+                ("background-color-focus", "set_background_color_focus", Color),
+                ("placeholder|placeholder-text", "set_placeholder_text", Text),
+                ("placeholder-color", "set_placeholder_color", Color),
+                ("placeholder-style", "set_placeholder_style", FontStyle),
+                ("vpadding", "set_vpadding", Float),
+            ],
+            Self::ImageView => &[
+                ("size", "set_size", Size),
+                ("scale", "set_scale", Scale),
+                ("image", "set_image", Image),
+            ],
+            Self::Label => &[
+                ("text", "set_text", Localized("set_text_rid")),
+                ("width", "set_width", Float),
+                ("multiline|multi-line", "set_multiline", Bool),
+                ("align", "set_align", Align),
+                ("color", "set_color", Color),
+                ("color-over", "set_color_over", Color),
+                ("background-color", "set_background_color", Color),
+                ("background-color-over", "set_background_color_over", Color),
+                ("style-over", "set_style_over", FontStyle),
+            ],
+            Self::Panel => &[
+                ("size", "set_size", Size),
+                ("visible-layout", "set_visible_layout", UInt),
+            ],
+            Self::ListBox => &[
+                ("size", "size", Size),
+                ("checkbox", "set_checkbox", Bool),
+                ("multiselect", "set_multiselect", Bool),
+            ],
+            Self::PopUp => &[
+                ("tooltip", "set_tooltip", Text),
+                ("list-height", "set_list_height", UInt),
+                ("selected", "set_selected", UInt),
+            ],
+            Self::Progress => &[
+                ("width", "set_width", Float),
+                ("undefined", "set_undefined", Bool),
+                ("value", "set_value", Float),
+            ],
+            Self::Slider => &[
+                ("length", "set_length", Float),
+                ("tooltip", "set_tooltip", Text),
+                ("steps", "set_steps", UInt),
+                ("value", "set_value", Float),
+            ],
+            Self::SplitView => &[
+                ("first-visible", "set_first_visible", Bool),
+                ("last-visible", "set_last_visible", Bool),
+                ("first-min-size", "set_first_min_size", Float),
+                ("last-min-size", "set_last_min_size", Float),
+            ],
+            Self::TableView => &[
+                ("size", "set_size", Size),
+                ("column-freeze", "set_column_freeze", UInt),
+                ("header-visible", "set_header_visible", Bool),
+                ("header-clickable", "set_header_clickable", Bool),
+                ("header-resizable", "set_header_resizable", Bool),
+                ("header-height", "set_header_height", Float),
+                ("row-height", "set_row_height", Float),
+                ("multiselect", "set_multiselect", Bools),
+                ("grid", "set_grid", Bools),
+                ("scroll-visible", "set_scroll_visible", Bools),
+            ],
+            Self::Tabs => &[
+                ("length", "set_length", Float),
+                ("tooltip", "set_tooltip", Text),
+                ("selected", "set_selected", UInt),
+            ],
+            Self::TextView => &[
+                ("size", "set_size", Size),
+                ("units", "set_units", UInt),
+                ("font-family", "set_font_family", Text),
+                ("font-size", "set_font_size", Float),
+                ("font-style", "set_font_style", FontStyle),
+                ("color", "set_color", Color),
+                ("background-color", "set_background_color", Color),
+                ("align|horizontal-align", "set_horizontal_align", Align),
+                ("line-spacing", "set_line_spacing", Float),
+                ("before-space", "set_before_space", Float),
+                ("after-space", "set_after_space", Float),
+                ("scroll-visible", "set_scroll_visible", Bools),
+                ("editable", "set_editable", Bool),
+                ("select", "set_select", Ints),
+                ("show-select", "set_show_select", Bool),
+            ],
+            Self::UpDown => &[("tooltip", "set_tooltip", Text)],
+            Self::View => &[
+                ("size", "set_size", Size),
+                ("tooltip", "set_tooltip", Text),
+                ("allow-tab", "set_allow_tab", Bool),
+                ("scroll-visible", "set_scroll_visible", Bools),
+            ],
+            Self::WebView => &[("size", "set_size", Size)],
+            Self::Line => &[("length", "set_length", Float)],
+            Self::Layout => &[
+                ("margin", "set_margin", Margin),
+                ("taborder|tab-order", "set_taborder", Orient),
+                ("background-color", "set_background_color", Color),
+                ("border-color", "set_border_color", Color),
+            ],
+            Self::Window => &[
+                ("title", "set_title", Localized("set_title_rid")),
+                ("client-size|size", "set_client_size", Size),
+                ("origin", "set_origin", Size),
+            ],
+            Self::MenuItem => &[
+                ("text", "set_text", Localized("set_text_rid")),
+                ("image", "set_image", Image),
+                ("state", "set_state", State),
+                ("enabled", "set_enabled", Bool),
+                ("visible", "set_visible", Bool),
+            ],
+            _ => &[],
+        }
+    }
+
+    /// The event handlers that can be declared as attributes of each type of node.
+    fn events(&self) -> &'static [Event] {
+        match self {
+            Self::Button => &[("on-click", "set_on_click_handler", "Fn(&ButtonEvent)")],
+            Self::Combo => &[
+                (
+                    "on-filter",
+                    "set_on_filter_handler",
+                    "Fn(&TextEvent) -> TextFilterEvent",
+                ),
+                ("on-change", "set_on_change_handler", "Fn(&TextEvent) -> bool"),
+                ("on-focus", "set_on_focus_handler", "Fn(&bool)"),
+                ("on-select", "set_on_select_handler", "Fn(&ButtonEvent)"),
+            ],
+            Self::Edit => &[
+                (
+                    "on-filter",
+                    "set_on_filter_handler",
+                    "Fn(&TextEvent) -> TextFilterEvent",
+                ),
+                ("on-change", "set_on_change_handler", "Fn(&TextEvent) -> bool"),
+                ("on-focus", "set_on_focus_handler", "Fn(&bool)"),
+            ],
+            Self::ImageView => &[
+                ("on-click", "set_on_click_handler", "Fn()"),
+                ("on-over-draw", "set_on_over_draw_handler", "Fn()"),
+            ],
+            Self::Label => &[("on-click", "set_on_click_handler", "Fn(&MouseEvent)")],
+            Self::Layout => &[(
+                "on-dbind-change|on-change",
+                "set_on_dbind_change_handler",
+                "Fn(&DBindChangeEvent)",
+            )],
+            Self::ListBox => &[
+                ("on-down", "set_on_down_handler", "Fn(&MouseEvent) -> bool"),
+                ("on-select", "set_on_select_handler", "Fn(&ButtonEvent)"),
+            ],
+            Self::PopUp => &[("on-select", "set_on_select_handler", "Fn(&ButtonEvent)")],
+            Self::Slider => &[("on-moved", "set_on_moved_handler", "Fn(&SliderEvent)")],
+            Self::TableView => &[
+                ("on-select", "set_on_select_handler", "Fn()"),
+                ("on-row-click", "set_on_row_click_handler", "Fn()"),
+                ("on-header-click", "set_on_header_click_handler", "Fn()"),
+                (
+                    "on-data",
+                    "set_on_data_handler",
+                    "Fn(&TableDataParams) -> TableDataResult",
+                ),
+                ("on-expand", "set_on_expand_handler", "Fn(&TableExpandEvent)"),
+            ],
+            Self::Tabs => &[("on-select", "set_on_select_handler", "Fn(&ButtonEvent)")],
+            Self::TextView => &[
+                (
+                    "on-filter",
+                    "set_on_filter_handler",
+                    "Fn(&TextEvent) -> TextFilterEvent",
+                ),
+                ("on-focus", "set_on_focus_handler", "Fn(&bool)"),
+            ],
+            Self::UpDown => &[("on-click", "set_on_click_handler", "Fn(&ButtonEvent)")],
+            Self::View => &[
+                ("on-draw", "set_on_draw_handler", "Fn(&DrawEvent)"),
+                ("on-overlay", "set_on_overlay_handler", "Fn(&DrawEvent)"),
+                ("on-size", "set_on_size_handler", "Fn(&SizeEvent)"),
+                ("on-enter", "set_on_enter_handler", "Fn(&MouseEvent)"),
+                ("on-exit", "set_on_exit_handler", "Fn(&MouseEvent)"),
+                ("on-move", "set_on_move_handler", "Fn(&MouseEvent)"),
+                ("on-down", "set_on_down_handler", "Fn(&MouseEvent)"),
+                ("on-up", "set_on_up_handler", "Fn(&MouseEvent)"),
+                ("on-click", "set_on_click_handler", "Fn(&MouseEvent)"),
+                ("on-drag", "set_on_drag_handler", "Fn(&MouseEvent)"),
+                ("on-wheel", "set_on_wheel_handler", "Fn(&MouseEvent)"),
+                ("on-key-down", "set_on_key_down_handler", "Fn(&KeyEvent)"),
+                ("on-key-up", "set_on_key_up_handler", "Fn(&KeyEvent)"),
+                ("on-focus", "set_on_focus_handler", "Fn(&bool)"),
+                ("on-accept-focus", "set_on_accept_focus_handler", "Fn() -> bool"),
+                ("on-resign-focus", "set_on_resign_focus_handler", "Fn() -> bool"),
+                ("on-scroll", "set_on_scroll_handler", "Fn(&ScrollEvent) -> f32"),
+            ],
+            Self::WebView => &[("on-focus", "set_on_focus_handler", "Fn(&bool)")],
+            Self::Window => &[
+                ("on-close", "set_on_close_handler", "Fn(&WindowCloseEvent) -> bool"),
+                ("on-moved", "set_on_moved_handler", "Fn(&PositionEvent)"),
+                ("on-resize", "set_on_resize_handler", "Fn(&SizeEvent)"),
+            ],
+            Self::MenuItem => &[("on-click", "on_click", "Fn(&MenuEvent)")],
+            _ => &[],
+        }
+    }
+}
+
+/// An event handler declared as an attribute: (attribute names, handler method, callback type).
+/// Alternative names of the attribute are separated by `|`.
+type Event = (&'static str, &'static str, &'static str);
+
+/// Setters of the `<item>` elements of a ListBox. The index of the item is the first argument.
+const LIST_ITEM_SETTERS: &[Setter] = &[
+    ("color", "set_color", AttrKind::Color),
+    ("checked|check", "check", AttrKind::Bool),
+];
+
+/// Setters of the `<column>` elements of a TableView. The column id is the first argument.
+const TABLE_COLUMN_SETTERS: &[Setter] = &[
+    ("title|text", "set_header_title", AttrKind::Text),
+    ("width", "set_column_width", AttrKind::Float),
+    ("limits", "set_column_limits", AttrKind::Size),
+    ("align", "set_column_align", AttrKind::Align),
+    ("header-align", "set_header_align", AttrKind::Align),
+    ("resizable", "set_column_resizable", AttrKind::Bool),
+];
+
+/// Setters of the `<column>` elements of a Layout. The column index is the first argument.
+const LAYOUT_COLUMN_SETTERS: &[Setter] = &[
+    ("width|size", "set_horizontal_size", AttrKind::Float),
+    ("margin", "set_horizontal_margin", AttrKind::Float),
+    ("visible", "set_column_visible", AttrKind::Bool),
+];
+
+/// Setters of the `<row>` elements of a Layout. The row index is the first argument.
+const LAYOUT_ROW_SETTERS: &[Setter] = &[
+    ("height|size", "set_vertical_size", AttrKind::Float),
+    ("margin", "set_vertical_margin", AttrKind::Float),
+    ("visible", "set_row_visible", AttrKind::Bool),
+];
+
+/// Setters of the `<cell>` elements of a Layout. The column and row are the first arguments.
+const CELL_SETTERS: &[Setter] = &[
+    ("halign|horizontal-align", "set_horizontal_align", AttrKind::Align),
+    ("valign|vertical-align", "set_vertical_align", AttrKind::Align),
+    ("tabstop", "set_tabstop", AttrKind::Bool),
+    ("enabled", "set_enabled", AttrKind::Bool),
+    ("visible", "set_visible", AttrKind::Bool),
+    ("padding", "set_padding", AttrKind::Margin),
+];
+
+/// Calls the setters whose attribute is declared in the node. `args` are passed before the value.
+fn setter_calls(
+    node: &GeneratorNode,
+    target: &TokenStream,
+    args: &TokenStream,
+    setters: &[Setter],
+) -> Vec<TokenStream> {
+    setters
+        .iter()
+        .filter_map(|(attr, setter, kind)| {
+            let value = node.attr(attr)?;
+            // Localized texts are set again when the language changes.
+            let (setter, value) = match (kind, resource_text(value)) {
+                (AttrKind::Localized(rid_setter), Some(rid)) => (rid_setter, resource_id(rid)?),
+                _ => (setter, kind.args(value)?),
+            };
+            let setter = Ident::new(setter, Span::call_site());
+            Some(quote! { #target.#setter(#args #value); })
+        })
+        .collect()
+}
+
+/// The kind of value of an attribute, which determines how it is converted to setter arguments.
+#[derive(Clone, Copy)]
+enum AttrKind {
+    /// A string. `@HELLO_MSG` is a text from the registered resources, `@@` is a literal `@`.
+    Text,
+    /// A text like [`AttrKind::Text`], but a resource is set with the given setter, which sets it
+    /// again when the language changes.
+    Localized(&'static str),
+    /// A `f32` number.
+    Float,
+    /// A `u32` number.
+    UInt,
+    /// `true`, `false`, `1` or `0`.
+    Bool,
+    /// Two booleans, like `true,false`.
+    Bools,
+    /// Two `i32` numbers, like `0,5`.
+    Ints,
+    /// Two `f32` numbers, like `200,100`.
+    Size,
+    /// One `f32` number for all sides, or four for top, right, bottom and left.
+    Margin,
+    /// `left`, `center`, `right` or `justify`.
+    Align,
+    /// A color in HTML format, like `#FF0000`.
+    Color,
+    /// Font styles separated by `|`, like `bold|italic`.
+    FontStyle,
+    /// `on`, `off` or `mixed`.
+    State,
+    /// `none`, `auto`, `aspect`, `aspectdw` or `adjust`.
+    Scale,
+    /// `left`, `top`, `right`, `bottom` or `none`.
+    Position,
+    /// `horizontal` or `vertical`.
+    Orient,
+    /// Path of an image file, relative to the source file. The image is embedded in the binary.
+    /// `@ICON_PNG` is an image from the registered resources.
+    Image,
+    /// A Rust identifier, used for names and handlers.
+    Name,
+    /// One of the given options.
+    Choice(&'static [&'static str]),
+}
+
+impl AttrKind {
+    /// Convert the value of the attribute to the arguments of the setter.
+    /// Returns None if the value is not valid.
+    fn args(self, value: &str) -> Option<TokenStream> {
+        let args = match self {
+            AttrKind::Text | AttrKind::Localized(_) => match resource_text(value) {
+                Some(rid) => {
+                    let rid = resource_id(rid)?;
+                    quote! { &gui_text(#rid) }
+                }
+                None => {
+                    // `@@` is an escaped `@`.
+                    let text = value.strip_prefix('@').unwrap_or(value);
+                    let value = LitStr::new(text, Span::call_site());
+                    quote! { #value }
+                }
+            },
+            AttrKind::Float => {
+                let value = value.trim().parse::<f32>().ok()?;
+                quote! { #value }
+            }
+            AttrKind::UInt => {
+                let value = value.trim().parse::<u32>().ok()?;
+                quote! { #value }
+            }
+            AttrKind::Bool => {
+                let value = parse_bool(value)?;
+                quote! { #value }
+            }
+            AttrKind::Bools => {
+                let values = parse_list(value, parse_bool)?;
+                let [first, second] = values.as_slice() else {
+                    return None;
+                };
+                quote! { #first, #second }
+            }
+            AttrKind::Ints => {
+                let values = parse_list(value, |value| value.parse::<i32>().ok())?;
+                let [first, second] = values.as_slice() else {
+                    return None;
+                };
+                quote! { #first, #second }
+            }
+            AttrKind::Size => {
+                let values = parse_list(value, |value| value.parse::<f32>().ok())?;
+                let [first, second] = values.as_slice() else {
+                    return None;
+                };
+                quote! { #first, #second }
+            }
+            AttrKind::Margin => {
+                let values = parse_list(value, |value| value.parse::<f32>().ok())?;
+                match values.as_slice() {
+                    [all] => quote! { #all, #all, #all, #all },
+                    [top, right, bottom, left] => quote! { #top, #right, #bottom, #left },
+                    _ => return None,
+                }
+            }
+            AttrKind::Align => {
+                let align = variant(
+                    value,
+                    &[
+                        ("left", "Left"),
+                        ("center", "Center"),
+                        ("right", "Right"),
+                        ("justify", "Justify"),
+                    ],
+                )?;
+                quote! { Align::#align }
+            }
+            AttrKind::Color => {
+                let value = LitStr::new(value.trim(), Span::call_site());
+                quote! { Color::html(#value) }
+            }
+            AttrKind::FontStyle => {
+                let styles = value
+                    .split('|')
+                    .map(|style| {
+                        variant(
+                            style,
+                            &[
+                                ("normal", "Normal"),
+                                ("bold", "Bold"),
+                                ("italic", "Italic"),
+                                ("strike_out|strike-out", "StrikeOut"),
+                                ("underlined", "Underlined"),
+                                ("subscript", "Subscript"),
+                                ("superscript", "Superscript"),
+                                ("points", "Points"),
+                                ("cell", "Cell"),
+                            ],
+                        )
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                quote! { #(FontStyle::#styles)|* }
+            }
+            AttrKind::State => {
+                let state = variant(value, &[("on", "On"), ("off", "Off"), ("mixed", "Mixed")])?;
+                quote! { GuiState::#state }
+            }
+            AttrKind::Scale => {
+                let scale = variant(
+                    value,
+                    &[
+                        ("none", "None"),
+                        ("auto", "Auto"),
+                        ("aspect", "Aspect"),
+                        ("aspectdw", "AspectDW"),
+                        ("adjust", "Adjust"),
+                    ],
+                )?;
+                quote! { Scale::#scale }
+            }
+            AttrKind::Position => {
+                let position = variant(
+                    value,
+                    &[
+                        ("none", "None"),
+                        ("left", "Left"),
+                        ("top", "Top"),
+                        ("right", "Right"),
+                        ("bottom", "Bottom"),
+                    ],
+                )?;
+                quote! { GuiPosition::#position }
+            }
+            AttrKind::Orient => {
+                let orient = variant(value, &[("horizontal", "Horizontal"), ("vertical", "Vertical")])?;
+                quote! { GuiOrient::#orient }
+            }
+            AttrKind::Image => {
+                if let Some(rid) = value.trim().strip_prefix('@') {
+                    let rid = resource_id(rid)?;
+                    return Some(quote! { gui_image(#rid) });
+                }
+                if value.trim().is_empty() {
+                    return None;
+                }
+                let path = LitStr::new(value.trim(), Span::call_site());
+                quote! { &Image::from_data(include_bytes!(#path)) }
+            }
+            AttrKind::Name => {
+                let name = parse_ident(value)?;
+                quote! { #name }
+            }
+            AttrKind::Choice(options) => {
+                let value = value.trim().to_lowercase();
+                if !options.contains(&value.as_str()) {
+                    return None;
+                }
+                let value = LitStr::new(&value, Span::call_site());
+                quote! { #value }
+            }
+        };
+        Some(args)
+    }
+
+    /// Description of the valid values, used in the error messages.
+    fn expected(self) -> String {
+        match self {
+            AttrKind::Text | AttrKind::Localized(_) => "a text, or a resource like `@HELLO_MSG`".to_string(),
+            AttrKind::Float => "a number".to_string(),
+            AttrKind::UInt => "a positive integer".to_string(),
+            AttrKind::Bool => "`true` or `false`".to_string(),
+            AttrKind::Bools => "two booleans, like `true,false`".to_string(),
+            AttrKind::Ints => "two integers, like `0,5`".to_string(),
+            AttrKind::Size => "two numbers, like `200,100`".to_string(),
+            AttrKind::Margin => "one number, or four numbers for top, right, bottom and left".to_string(),
+            AttrKind::Align => "`left`, `center`, `right` or `justify`".to_string(),
+            AttrKind::Color => "a color, like `#FF0000`".to_string(),
+            AttrKind::FontStyle => "font styles separated by `|`, like `bold|italic`".to_string(),
+            AttrKind::State => "`on`, `off` or `mixed`".to_string(),
+            AttrKind::Scale => "`none`, `auto`, `aspect`, `aspectdw` or `adjust`".to_string(),
+            AttrKind::Position => "`none`, `left`, `top`, `right` or `bottom`".to_string(),
+            AttrKind::Orient => "`horizontal` or `vertical`".to_string(),
+            AttrKind::Image => "the path of an image file, or a resource like `@ICON_PNG`".to_string(),
+            AttrKind::Name => "a Rust identifier".to_string(),
+            AttrKind::Choice(options) => {
+                let options = options.iter().map(|option| format!("`{}`", option)).collect::<Vec<_>>();
+                format!("one of {}", options.join(", "))
+            }
+        }
+    }
+}
+
+/// Parses a Rust identifier. Raw identifiers like `r#type` are not accepted, because the names
+/// are also part of the generated identifiers.
+fn parse_ident(value: &str) -> Option<Ident> {
+    syn::parse_str::<Ident>(value)
+        .ok()
+        .filter(|ident| !value.starts_with("r#") && *ident == value)
+}
+
+/// The resource of a text like `@HELLO_MSG`, or None if it is a literal text. `@@` is an escaped `@`.
+fn resource_text(value: &str) -> Option<&str> {
+    value.strip_prefix('@').filter(|rid| !rid.starts_with('@'))
+}
+
+/// The path of a resource ID generated by `include_resource!`, like `HELLO_MSG` in `@HELLO_MSG`.
+/// Relative paths are resolved from the module that calls the macro, which is the parent of the
+/// generated module.
+fn resource_id(rid: &str) -> Option<TokenStream> {
+    let rid = rid.trim();
+    let rid = rid.strip_prefix("self::").unwrap_or(rid);
+    let path = syn::parse_str::<syn::Path>(rid).ok()?;
+    let absolute = path.leading_colon.is_some() || path.segments.first()?.ident == "crate";
+    if absolute {
+        Some(quote! { #path })
+    } else {
+        Some(quote! { super::#path })
+    }
+}
+
+/// Find the variant of an enum from its name in XML. Alternative names are separated by `|`.
+fn variant(value: &str, variants: &[(&str, &str)]) -> Option<Ident> {
+    let value = value.trim().to_lowercase();
+    variants
+        .iter()
+        .find(|(names, _)| names.split('|').any(|name| name == value))
+        .map(|(_, variant)| Ident::new(variant, Span::call_site()))
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "1" => Some(true),
+        "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parse a list of values separated by commas.
+fn parse_list<T>(value: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Vec<T>> {
+    value.split(',').map(|value| parse(value.trim())).collect()
+}
+
 trait FromStr {
     fn from_str(s: &str) -> Self;
 }
@@ -0,0 +1,525 @@
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::Span;
+use syn::LitStr;
+
+use super::{
+    parse_bool, parse_ident, AttrKind, FieldType, Generator, GeneratorNode, CELL_SETTERS, LAYOUT_COLUMN_SETTERS,
+    LAYOUT_ROW_SETTERS, LIST_ITEM_SETTERS, TABLE_COLUMN_SETTERS,
+};
+
+/// An attribute that can be declared in a node: (attribute names, kind of value).
+/// Alternative names of the attribute are separated by `|`.
+type Attr = (&'static str, AttrKind);
+
+const FONT_ATTRS: &[Attr] = &[
+    ("font-family", AttrKind::Text),
+    ("font-size", AttrKind::Float),
+    ("font-style", AttrKind::FontStyle),
+];
+
+const LAYOUT_INDEX_ATTRS: &[Attr] = &[("expand", AttrKind::Bool), ("weight", AttrKind::Float)];
+
+/// The XML of the macro, from a literal or a file. The errors are reported at the position of
+/// the XML where they are found.
+pub(super) struct Source {
+    lit: LitStr,
+    xml: String,
+    path: Option<PathBuf>,
+}
+
+impl Source {
+    pub(super) fn new(lit: LitStr) -> Self {
+        let xml = lit.value();
+        Self { lit, xml, path: None }
+    }
+
+    /// Read the XML from a file. The errors point to the literal with the path of the file.
+    pub(super) fn from_file(lit: LitStr, path: &Path) -> Result<Self, syn::Error> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let xml = std::fs::read_to_string(&path)
+            .map_err(|err| syn::Error::new(lit.span(), format!("unable to read `{}`: {}", path.display(), err)))?;
+        Ok(Self {
+            lit,
+            xml,
+            path: Some(path),
+        })
+    }
+
+    pub(super) fn xml(&self) -> &str {
+        &self.xml
+    }
+
+    pub(super) fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Name of the XML in the error messages.
+    fn origin(&self) -> String {
+        match &self.path {
+            Some(_) => format!("`{}`", self.lit.value()),
+            None => "XML".to_string(),
+        }
+    }
+
+    /// Creates an error for a malformed XML document.
+    pub(super) fn xml_error(&self, err: &roxmltree::Error) -> syn::Error {
+        syn::Error::new(self.lit.span(), format!("invalid XML in {}: {}", self.origin(), err))
+    }
+
+    /// Creates an error at a range of the XML.
+    ///
+    /// # Remarks
+    /// The error points to the range inside the literal if the compiler supports it, otherwise to
+    /// the entire literal. The line and column in the XML are always included in the message.
+    pub(super) fn error(&self, range: Range<usize>, message: impl Display) -> syn::Error {
+        let before = &self.xml[..range.start];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+        syn::Error::new(
+            self.span(range),
+            format!("{} ({} line {}, column {})", message, self.origin(), line, column),
+        )
+    }
+
+    fn span(&self, range: Range<usize>) -> Span {
+        let token = self.lit.token();
+        let repr = token.to_string();
+        // The XML can only be located in literals without escape sequences, like raw strings.
+        let offset = repr
+            .find('"')
+            .map(|quote| quote + 1)
+            .filter(|offset| repr.get(*offset..*offset + self.xml.len()) == Some(self.xml.as_str()));
+        offset
+            .and_then(|offset| token.subspan(offset + range.start..offset + range.end))
+            .unwrap_or_else(|| self.lit.span())
+    }
+}
+
+impl Generator {
+    /// Checks the elements and attributes of the XML, so the errors are reported in the XML
+    /// instead of in the generated code.
+    pub(super) fn validate(&self, source: &Source) -> Result<(), syn::Error> {
+        let mut errors = Vec::new();
+        let root = self.node(self.root.unwrap());
+        check_attrs(root, &[("mod|module|namespacing", AttrKind::Name)], source, &mut errors);
+
+        let mut structs = HashSet::new();
+        for id in self.defined_nodes() {
+            let node = self.node(*id);
+            match (&node.ty, node.attr("inherits|extends")) {
+                (FieldType::Custom(name), Some(_)) => {
+                    if parse_ident(name).is_none() {
+                        errors.push(source.error(node.range.clone(), format!("invalid struct name `{}`", name)));
+                    } else if !structs.insert(name) {
+                        errors.push(source.error(node.range.clone(), format!("duplicate struct `{}`", name)));
+                    }
+                    self.validate_struct(*id, source, &mut errors);
+                }
+                (FieldType::Custom(name), None) => errors.push(source.error(
+                    node.range.clone(),
+                    format!(
+                        "`<{}>` should inherit from a control, like `<{} inherits=\"Window\">`",
+                        name, name
+                    ),
+                )),
+                _ => errors.push(source.error(
+                    node.range.clone(),
+                    format!(
+                        "`<{}>` can't be declared in `<{}>`, declare a struct like `<MyWindow inherits=\"Window\">`",
+                        node.tag, root.tag
+                    ),
+                )),
+            }
+        }
+
+        match errors.into_iter().reduce(|mut error, other| {
+            error.combine(other);
+            error
+        }) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks a user defined struct and all its nodes.
+    fn validate_struct(&self, id: usize, source: &Source, errors: &mut Vec<syn::Error>) {
+        let node = self.node(id);
+        let mut attrs = vec![("inherits|extends", AttrKind::Name)];
+        attrs.extend(known_attrs(&node.base_type(), None));
+        check_attrs(node, &attrs, source, errors);
+
+        // The ids of the nodes follow the document order, so the errors are reported in order.
+        let mut child_nodes = self.child_nodes(id);
+        child_nodes.sort();
+        let mut names = HashSet::new();
+        let mut setters = HashSet::new();
+        for node in std::iter::once(id)
+            .chain(child_nodes.iter().copied())
+            .map(|id| self.node(id))
+        {
+            if let Some(name) = node.attr("name") {
+                if !names.insert(name) {
+                    errors.push(source.error(node.attr_range("name"), format!("duplicate name `{}`", name)));
+                }
+            }
+            let ty = node.base_type();
+            let events = ty.events().iter().map(|(attr, _, _)| *attr);
+            let write = (ty == FieldType::TextView).then_some("write");
+            for attr in events.chain(write) {
+                if let Some(handler) = node.attr(attr) {
+                    if !setters.insert(handler) {
+                        errors.push(source.error(node.attr_range(attr), format!("duplicate handler `{}`", handler)));
+                    }
+                }
+            }
+        }
+
+        // References to other nodes of the struct.
+        for node in std::iter::once(id)
+            .chain(child_nodes.iter().copied())
+            .map(|id| self.node(id))
+        {
+            for attr in ["for|control", "default-button"] {
+                if let Some(name) = node.attr(attr) {
+                    if parse_ident(name).is_some() && !names.contains(name) {
+                        errors.push(source.error(node.attr_range(attr), format!("`{}` is not declared", name)));
+                    }
+                }
+            }
+        }
+
+        // The last expanded column (row) takes the rest of the space, so a weight would be ignored.
+        for &layout in child_nodes
+            .iter()
+            .filter(|id| self.node(**id).base_type() == FieldType::Layout)
+        {
+            for (ty, what) in [(FieldType::Column, "column"), (FieldType::Row, "row")] {
+                let last = self
+                    .children_of(layout, ty)
+                    .filter(|line| line.attr("expand").and_then(parse_bool).unwrap_or(false))
+                    .last();
+                if let Some(line) = last.filter(|line| line.attr("weight").is_some()) {
+                    errors.push(source.error(
+                        line.attr_range("weight"),
+                        format!(
+                            "the last expanded {} takes the rest of the space, it can't have a `weight`",
+                            what
+                        ),
+                    ));
+                }
+            }
+        }
+
+        for child in child_nodes {
+            let node = self.node(child);
+            let parent = self.node(node.parent.unwrap());
+            let parent_ty = parent.base_type();
+            if let Some(message) = check_placement(node, parent) {
+                errors.push(source.error(node.range.clone(), message));
+                continue;
+            }
+            check_attrs(node, &known_attrs(&node.base_type(), Some(&parent_ty)), source, errors);
+            if let (FieldType::Item, None, Some(text)) = (&node.ty, node.attr("text"), &node.text) {
+                if AttrKind::Text.args(text).is_none() {
+                    errors.push(source.error(
+                        node.range.clone(),
+                        format!("invalid text `{}`, expected {}", text, AttrKind::Text.expected()),
+                    ));
+                }
+            }
+
+            // Cells, columns and rows must be inside the layout.
+            if parent_ty == FieldType::Layout {
+                let columns = parent.uint_attr("cols|columns").unwrap_or(1);
+                let rows = parent.uint_attr("rows").unwrap_or(1);
+                let (column, row) = match node.ty {
+                    FieldType::Cell => ("column|col", "row"),
+                    FieldType::Column => ("index|column|col", ""),
+                    FieldType::Row => ("", "index|row"),
+                    _ => ("", ""),
+                };
+                for (attr, size, what) in [(column, columns, "column"), (row, rows, "row")] {
+                    if attr.is_empty() {
+                        continue;
+                    }
+                    match node.uint_attr(attr) {
+                        Some(index) if index >= size => errors.push(source.error(
+                            node.attr_range(attr),
+                            format!(
+                                "index {} is out of range, the layout has {} {}{}",
+                                index,
+                                size,
+                                what,
+                                if size == 1 { "" } else { "s" }
+                            ),
+                        )),
+                        Some(_) => (),
+                        None if node.attr(attr).is_none() => errors.push(source.error(
+                            node.range.clone(),
+                            format!(
+                                "`<{}>` requires the `{}` attribute",
+                                node.tag,
+                                attr.replace('|', "` or `")
+                            ),
+                        )),
+                        None => (),
+                    }
+                }
+                if node.ty == FieldType::Cell && node.attr("for|control").is_none() {
+                    errors.push(source.error(
+                        node.range.clone(),
+                        format!("`<{}>` requires the `for` attribute", node.tag),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl GeneratorNode {
+    /// Position of the value of an attribute, or of the tag name if it is not declared.
+    fn attr_range(&self, name: &str) -> Range<usize> {
+        let names: Vec<&str> = name.split('|').collect();
+        self.attr_ranges
+            .iter()
+            .find(|(attr, _, _)| names.contains(&attr.as_str()))
+            .map(|(_, _, value)| value.clone())
+            .unwrap_or_else(|| self.range.clone())
+    }
+}
+
+/// Checks that a node can be declared in its parent. Returns the error message if not.
+fn check_placement(node: &GeneratorNode, parent: &GeneratorNode) -> Option<String> {
+    let parent_ty = parent.base_type();
+    if matches!(
+        parent_ty,
+        FieldType::Cell | FieldType::Item | FieldType::Column | FieldType::Row
+    ) {
+        return Some(format!("`<{}>` can't have child elements", parent.tag));
+    }
+    let valid = match &node.ty {
+        // User defined structs are types, so they start with an uppercase letter.
+        FieldType::Custom(tag) => {
+            if !tag.starts_with(|c: char| c.is_ascii_uppercase()) {
+                return Some(format!("unknown element `<{}>`", node.tag));
+            }
+            true
+        }
+        FieldType::Cell | FieldType::Row => parent_ty == FieldType::Layout,
+        FieldType::Column => matches!(parent_ty, FieldType::Layout | FieldType::TableView),
+        FieldType::Item => matches!(
+            parent_ty,
+            FieldType::ListBox | FieldType::PopUp | FieldType::Combo | FieldType::Tabs
+        ),
+        FieldType::Layout => matches!(parent_ty, FieldType::Panel | FieldType::Layout),
+        FieldType::Menu => matches!(parent_ty, FieldType::Window | FieldType::MenuItem),
+        FieldType::MenuItem => parent_ty == FieldType::Menu,
+        _ => true,
+    };
+    (!valid).then(|| format!("`<{}>` can't be declared in `<{}>`", node.tag, parent.tag))
+}
+
+/// The attributes that can be declared in a node of a type, which depend on the type of the parent
+/// for `<item>` and `<column>` elements.
+fn known_attrs(ty: &FieldType, parent: Option<&FieldType>) -> Vec<Attr> {
+    let mut attrs = vec![("name", AttrKind::Name)];
+    attrs.extend(ty.setters().iter().map(|(attr, _, kind)| (*attr, *kind)));
+    attrs.extend(ty.events().iter().map(|(attr, _, _)| (*attr, AttrKind::Name)));
+    attrs.extend_from_slice(match ty {
+        FieldType::Button | FieldType::Label | FieldType::Edit | FieldType::Combo | FieldType::ListBox => FONT_ATTRS,
+        FieldType::TableView => FONT_ATTRS,
+        _ => &[],
+    });
+    let setters = match (ty, parent) {
+        (FieldType::Cell, _) => CELL_SETTERS,
+        (FieldType::Item, Some(FieldType::ListBox)) => LIST_ITEM_SETTERS,
+        (FieldType::Column, Some(FieldType::TableView)) => TABLE_COLUMN_SETTERS,
+        (FieldType::Column, _) => LAYOUT_COLUMN_SETTERS,
+        (FieldType::Row, _) => LAYOUT_ROW_SETTERS,
+        _ => &[],
+    };
+    attrs.extend(setters.iter().map(|(attr, _, kind)| (*attr, *kind)));
+    attrs.extend_from_slice(match (ty, parent) {
+        (FieldType::Button, _) => &[(
+            "type",
+            AttrKind::Choice(&["push", "check", "check3", "radio", "flat", "flatgle"]),
+        )],
+        (FieldType::Edit, _) => &[("multiline|multi-line", AttrKind::Bool)],
+        (FieldType::Panel, _) => &[
+            ("horizontal-scroll|horizontal_scroll|hscroll", AttrKind::Bool),
+            ("vertical-scroll|vertical_scroll|vscroll", AttrKind::Bool),
+            ("border|has-border", AttrKind::Bool),
+        ],
+        (FieldType::Slider | FieldType::Line, _) => &[("vertical", AttrKind::Bool)],
+        (FieldType::SplitView, _) => &[
+            ("vertical", AttrKind::Bool),
+            ("position|pos", AttrKind::Float),
+            ("split-mode|mode", AttrKind::Choice(&["normal", "fixed0", "fixed1"])),
+        ],
+        (FieldType::Tabs, _) => &[("position|pos", AttrKind::Choice(&["top", "bottom", "left", "right"]))],
+        (FieldType::TextView, _) => &[("write", AttrKind::Name)],
+        (FieldType::Layout, _) => &[
+            ("cols|columns", AttrKind::UInt),
+            ("rows", AttrKind::UInt),
+            ("group", AttrKind::Text),
+        ],
+        (FieldType::Window, _) => &[("default-button", AttrKind::Name)],
+        (FieldType::MenuItem, _) => &[("separator", AttrKind::Bool)],
+        (FieldType::Cell, _) => &[
+            ("for|control", AttrKind::Name),
+            ("column|col", AttrKind::UInt),
+            ("row", AttrKind::UInt),
+        ],
+        (FieldType::Item, _) => &[
+            ("text", AttrKind::Text),
+            ("image", AttrKind::Image),
+            ("selected", AttrKind::Bool),
+        ],
+        (FieldType::Column, Some(FieldType::TableView)) => &[],
+        (FieldType::Column, _) => &[("index|column|col", AttrKind::UInt)],
+        (FieldType::Row, _) => &[("index|row", AttrKind::UInt)],
+        _ => &[],
+    });
+    if matches!(ty, FieldType::Column | FieldType::Row) && parent == Some(&FieldType::Layout) {
+        attrs.extend_from_slice(LAYOUT_INDEX_ATTRS);
+    }
+    attrs
+}
+
+/// Checks that the attributes of a node are known and their values are valid.
+fn check_attrs(node: &GeneratorNode, attrs: &[Attr], source: &Source, errors: &mut Vec<syn::Error>) {
+    for (name, name_range, value_range) in &node.attr_ranges {
+        let Some((_, kind)) = attrs
+            .iter()
+            .find(|(names, _)| names.split('|').any(|attr| attr == name))
+        else {
+            errors.push(source.error(
+                name_range.clone(),
+                format!("unknown attribute `{}` for `<{}>`", name, node.tag),
+            ));
+            continue;
+        };
+        let value = &node.attrs[name];
+        if kind.args(value).is_none() {
+            errors.push(source.error(
+                value_range.clone(),
+                format!("invalid value `{}` for `{}`, expected {}", value, name, kind.expected()),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Validates a window declared in XML and returns the error messages.
+    fn errors(body: &str) -> Vec<String> {
+        let xml = format!(
+            r#"<UI mod="ui"><MainWindow inherits="Window">{}</MainWindow></UI>"#,
+            body
+        );
+        let source = Source::new(LitStr::new(&xml, Span::call_site()));
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+        match Generator::from_xml_doc(&doc).validate(&source) {
+            Ok(()) => Vec::new(),
+            Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn valid_xml() {
+        let body = r#"<Panel><layout cols="2"><cell for="ok" column="1" row="0"/></layout>
+            <button name="ok" text="OK" on-click="ok_click"/></Panel>"#;
+        assert_eq!(errors(body), Vec::<String>::new());
+    }
+
+    #[test]
+    fn unknown_element() {
+        let errors = errors(r#"<Panel><layout><buton/></layout></Panel>"#);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("unknown element `<buton>`"), "{}", errors[0]);
+    }
+
+    #[test]
+    fn unknown_attribute() {
+        let errors = errors(r#"<Panel><layout><label txt="Hello"/></layout></Panel>"#);
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].starts_with("unknown attribute `txt` for `<label>`"),
+            "{}",
+            errors[0]
+        );
+    }
+
+    #[test]
+    fn undeclared_for() {
+        let errors = errors(r#"<Panel><layout><cell for="missing" column="0" row="0"/></layout></Panel>"#);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("`missing` is not declared"), "{}", errors[0]);
+    }
+
+    #[test]
+    fn cell_out_of_range() {
+        let errors = errors(
+            r#"<Panel><layout cols="2" rows="1"><label name="a"/><cell for="a" column="2" row="0"/></layout></Panel>"#,
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].starts_with("index 2 is out of range, the layout has 2 columns"),
+            "{}",
+            errors[0]
+        );
+    }
+
+    #[test]
+    fn duplicate_name() {
+        let errors = errors(r#"<Panel><layout><label name="a"/><button name="a"/></layout></Panel>"#);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("duplicate name `a`"), "{}", errors[0]);
+    }
+
+    #[test]
+    fn malformed_values() {
+        let errors = errors(r#"<Panel><layout><label width="wide" multiline="yes"/></layout></Panel>"#);
+        assert_eq!(errors.len(), 2);
+        assert!(
+            errors[0].starts_with("invalid value `wide` for `width`, expected a number"),
+            "{}",
+            errors[0]
+        );
+        assert!(
+            errors[1].starts_with("invalid value `yes` for `multiline`, expected `true` or `false`"),
+            "{}",
+            errors[1]
+        );
+    }
+
+    #[test]
+    fn raw_identifiers() {
+        let errors = errors(r#"<Panel><layout><button name="r#type" on-click="r#fn"/></layout></Panel>"#);
+        assert_eq!(errors.len(), 2);
+        assert!(
+            errors[0].starts_with("invalid value `r#type` for `name`"),
+            "{}",
+            errors[0]
+        );
+        assert!(
+            errors[1].starts_with("invalid value `r#fn` for `on-click`"),
+            "{}",
+            errors[1]
+        );
+    }
+
+    #[test]
+    fn position_in_message() {
+        let errors = errors("<Panel>\n  <layout>\n    <label txt=\"a\"/></layout></Panel>");
+        assert!(errors[0].ends_with("(XML line 3, column 12)"), "{}", errors[0]);
+    }
+}
@@ -3,3 +3,4 @@
 #include <osapp/osapp.h>
 #include <osapp/osmain.h>
 #include <inet/httpreq.h>
+#include <inet/json.h>
@@ -9529,4 +9529,16 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn http_exists(url: *const char_t) -> bool_t;
 }
+unsafe extern "C" {
+    pub fn json_read_imp(stm: *mut Stream, opts: *const JsonOpts, type_: *const char_t) -> *mut ::libc::c_void;
+}
+unsafe extern "C" {
+    pub fn json_write_imp(stm: *mut Stream, data: *const ::libc::c_void, opts: *const JsonOpts, type_: *const char_t);
+}
+unsafe extern "C" {
+    pub fn json_destroy_imp(data: *mut *mut ::libc::c_void, type_: *const char_t);
+}
+unsafe extern "C" {
+    pub fn json_destopt_imp(data: *mut *mut ::libc::c_void, type_: *const char_t);
+}
 pub type __builtin_va_list = *mut ::libc::c_char;
@@ -2,10 +2,23 @@ use nappgui::osapp::*;
 use nappgui::prelude::*;
 use nappgui_macros::DataBind;
 
+#[derive(DataBind, Clone, Copy)]
+#[repr(i32)]
+enum Level {
+    Low,
+    Medium,
+    High,
+}
+
 #[derive(DataBind)]
 struct Data {
     text: NappguiString,
     boolean: NappguiBoolean,
+    #[dbind(default = Level::Medium)]
+    level: Level,
+    #[dbind(range = 0..=100, precision = 0.5, increment = 5, suffix = "kg", default = 10)]
+    weight: f32,
+    history: ArrSt<u32>,
 }
 
 struct App {}
@@ -16,11 +29,14 @@ impl AppHandler for App {
         
         let panel = Panel::new();
 
-        let layout = Layout::new(1, 10);
+        let layout = Layout::new(2, 6);
         let text1 = Edit::new();
         let text2 = Edit::new();
         let check1 = Button::new_check();
         let check2 = Button::new_check();
+        let popup = PopUp::new();
+        let weight = Edit::new();
+        let updown = UpDown::new();
         check1.set_text("Check 1");
         check2.set_text("Check 2");
 
@@ -28,12 +44,18 @@ impl AppHandler for App {
         layout.set_control(0, 1, text2);
         layout.set_control(0, 2, check1);
         layout.set_control(0, 3, check2);
+        layout.set_control(0, 4, popup);
+        layout.set_control(0, 5, weight);
+        layout.set_control(1, 5, updown);
 
         layout.dbind("Data");
         layout.dbind_cell(0, 0, "text");
         layout.dbind_cell(0, 1, "text");
         layout.dbind_cell(0, 2, "boolean");
         layout.dbind_cell(0, 3, "boolean");
+        layout.dbind_cell(0, 4, "level");
+        layout.dbind_cell(0, 5, "weight");
+        layout.dbind_cell(1, 5, "weight");
 
         panel.add_layout(layout);
 
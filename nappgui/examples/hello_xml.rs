@@ -9,12 +9,21 @@ nappgui_ui!(
     <UI mod="ui">
     <HelloWindow inherits="Window" title="Hello, World!" origin="500.0,200.0">
     <Panel>
-        <layout rows="3" >
+        <layout rows="5" margin="10" >
+            <row index="0" margin="5" />
+            <row index="4" expand="true" />
             <cell for="label" column="0" row="0" />
-            <cell for="button" column="0" row="1" />
-            <cell for="textview" column="0" row="2" />
+            <cell for="popup" column="0" row="1" />
+            <cell for="slider" column="0" row="2" />
+            <cell for="button" column="0" row="3" halign="center" />
+            <cell for="textview" column="0" row="4" />
         </layout>
-        <label name="label" text="Hello, world!" />
+        <label name="label" text="Hello, world!" align="center" />
+        <popup name="popup">
+            <item>English</item>
+            <item text="Spanish" selected="true" />
+        </popup>
+        <slider name="slider" steps="10" value="0.5" on-moved="slider_moved" />
         <button name="button" text="Click me!" font-size="16.0" font-style="bold" on-click="button_click"/>
         <textview name="textview" write="click" />
     </Panel>
@@ -33,6 +42,9 @@ impl AppHandler for App {
             click.set(click.get() + 1);
             window.setter_click(&format!("Click: {}\n", click.get()));
         });
+        window.setter_slider_moved(move |params| {
+            window.setter_click(&format!("Slider: {:.1}\n", params.position));
+        });
         window.set_on_close_handler(|_| finish());
         window.show();
         Self {}
@@ -0,0 +1,27 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use nappgui::osapp::*;
+use nappgui::prelude::*;
+
+nappgui_ui!(file = "ui/hello.xml");
+
+struct App {}
+
+impl AppHandler for App {
+    fn create() -> Self {
+        let click = Rc::new(Cell::new(0));
+        let window = HelloWindow::new();
+        window.setter_button_click(move |_| {
+            click.set(click.get() + 1);
+            window.setter_click(&format!("Click: {}\n", click.get()));
+        });
+        window.set_on_close_handler(|_| finish());
+        window.show();
+        Self {}
+    }
+}
+
+fn main() {
+    osmain::<App>();
+}
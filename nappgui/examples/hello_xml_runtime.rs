@@ -0,0 +1,30 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use nappgui::gui::ui;
+use nappgui::osapp::*;
+
+struct App {}
+
+impl AppHandler for App {
+    fn create() -> Self {
+        // The XML is read when the application starts, so it can be edited without recompiling.
+        let ui = ui::load_file(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/ui/hello.xml")).unwrap();
+        let window = ui.window("HelloWindow").unwrap();
+        let button = ui.control("button").and_then(|control| control.as_button()).unwrap();
+        let textview = ui.control("textview").and_then(|control| control.as_textview()).unwrap();
+
+        let click = Rc::new(Cell::new(0));
+        button.set_on_click_handler(move |_| {
+            click.set(click.get() + 1);
+            textview.write(&format!("Click: {}\n", click.get()));
+        });
+        window.set_on_close_handler(|_| finish());
+        window.show();
+        Self {}
+    }
+}
+
+fn main() {
+    osmain::<App>();
+}
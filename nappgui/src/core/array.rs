@@ -0,0 +1,307 @@
+use std::{
+    ffi::{CStr, CString},
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use nappgui_sys::{
+    array_all, array_clear, array_clear_ptr, array_create, array_delete, array_delete_ptr, array_destroy,
+    array_destroy_ptr, array_get, array_insert, array_size, dbind_create_imp, dbind_destroy_imp,
+};
+
+use crate::{
+    core::{dbind::dbind_type_name, DBindType, DataBind},
+    error::NappguiError,
+};
+
+/// Array of structures stored one after the other in memory (`ArrSt(type)`). It can be a field
+/// of a DataBind object, with elements of basic types or of other DataBind types.
+///
+/// # Remarks
+/// The array dereferences to a slice, so all the slice methods are available.
+#[repr(transparent)]
+pub struct ArrSt<T: DBindType> {
+    inner: *mut nappgui_sys::Array,
+    _marker: PhantomData<T>,
+}
+
+impl<T: DBindType> ArrSt<T> {
+    /// The arrays are created and destroyed with the type name of the elements.
+    fn type_name() -> &'static CStr {
+        dbind_type_name(T::dbind_type())
+    }
+
+    /// Create an empty array.
+    pub fn new() -> Self {
+        let inner = unsafe { array_create(size_of::<T>() as _, Self::type_name().as_ptr()) };
+        assert!(!inner.is_null(), "Unable to create the array.");
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Inserts an element at a position of the array.
+    ///
+    /// # Panics
+    /// If index > len.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "Insertion index out of bounds.");
+        let elem = unsafe { array_insert(self.inner, index as _, 1) } as *mut T;
+        unsafe { std::ptr::write(elem, value) };
+    }
+
+    /// Adds an element at the end of the array.
+    pub fn push(&mut self, value: T) {
+        self.insert(self.len(), value);
+    }
+
+    /// Removes an element from the array and returns it.
+    ///
+    /// # Panics
+    /// If index >= len.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "Removal index out of bounds.");
+        let value = unsafe { std::ptr::read(array_get(self.inner, index as _) as *const T) };
+        unsafe { array_delete(self.inner, index as _, 1, None) };
+        value
+    }
+
+    /// Removes the last element of the array and returns it.
+    pub fn pop(&mut self) -> Option<T> {
+        (!self.is_empty()).then(|| self.remove(self.len() - 1))
+    }
+
+    /// Removes all the elements of the array.
+    pub fn clear(&mut self) {
+        unsafe {
+            std::ptr::drop_in_place(self.deref_mut() as *mut [T]);
+            array_clear(self.inner, None);
+        }
+    }
+}
+
+impl<T: DBindType> Deref for ArrSt<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        let size = unsafe { array_size(self.inner) } as usize;
+        if size == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(array_all(self.inner) as *const T, size) }
+    }
+}
+
+impl<T: DBindType> DerefMut for ArrSt<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        let size = unsafe { array_size(self.inner) } as usize;
+        if size == 0 {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(array_all(self.inner) as *mut T, size) }
+    }
+}
+
+impl<T: DBindType> Default for ArrSt<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DBindType + Clone> Clone for ArrSt<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: DBindType + PartialEq> PartialEq for ArrSt<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl<T: DBindType + Debug> Debug for ArrSt<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: DBindType> FromIterator<T> for ArrSt<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: DBindType> Extend<T> for ArrSt<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: DBindType> Drop for ArrSt<T> {
+    fn drop(&mut self) {
+        self.clear();
+        unsafe { array_destroy(&mut self.inner, None, Self::type_name().as_ptr()) };
+    }
+}
+
+unsafe impl<T: DBindType> DBindType for ArrSt<T> {
+    fn dbind_type() -> CString {
+        let ty = format!("ArrSt<{}>", T::dbind_type().to_string_lossy());
+        CString::new(ty).unwrap()
+    }
+
+    fn dbind_register_type() -> Result<(), NappguiError> {
+        T::dbind_register_type()
+    }
+}
+
+/// Array of pointers to objects (`ArrPt(type)`). It can be a field of a DataBind object, with
+/// elements of other DataBind types.
+///
+/// # Remarks
+/// The objects are created in DBind, so their type must be registered before adding elements.
+#[repr(transparent)]
+pub struct ArrPt<T: DataBind> {
+    inner: *mut nappgui_sys::Array,
+    _marker: PhantomData<T>,
+}
+
+impl<T: DataBind> ArrPt<T> {
+    /// The arrays are created and destroyed with the type name of the elements.
+    fn type_name() -> &'static CStr {
+        dbind_type_name(T::dbind_type())
+    }
+
+    fn elem(&self, index: usize) -> *mut T {
+        unsafe { *(array_get(self.inner, index as _) as *mut *mut T) }
+    }
+
+    /// Create an empty array.
+    pub fn new() -> Self {
+        let inner = unsafe { array_create(size_of::<*mut T>() as _, Self::type_name().as_ptr()) };
+        assert!(!inner.is_null(), "Unable to create the array.");
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the number of elements.
+    pub fn len(&self) -> usize {
+        unsafe { array_size(self.inner) as usize }
+    }
+
+    /// Checks if the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets an element of the array.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        (index < self.len()).then(|| unsafe { &*self.elem(index) })
+    }
+
+    /// Gets a mutable element of the array.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (index < self.len()).then(|| unsafe { &mut *self.elem(index) })
+    }
+
+    /// Iterates over the elements of the array.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len()).map(|index| unsafe { &*self.elem(index) })
+    }
+
+    /// Inserts an element at a position of the array.
+    ///
+    /// # Panics
+    /// If index > len or the type is not registered.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "Insertion index out of bounds.");
+        let obj = unsafe { dbind_create_imp(T::TYPE.as_ptr()) } as *mut T;
+        assert!(!obj.is_null(), "The type of the array elements is not registered.");
+        // The default values created by DBind are released by its own destructors.
+        drop(unsafe { std::ptr::replace(obj, value) });
+        let elem = unsafe { array_insert(self.inner, index as _, 1) } as *mut *mut T;
+        unsafe { *elem = obj };
+    }
+
+    /// Adds an element at the end of the array.
+    pub fn push(&mut self, value: T) {
+        self.insert(self.len(), value);
+    }
+
+    /// Removes and destroys an element of the array.
+    ///
+    /// # Panics
+    /// If index >= len.
+    pub fn remove(&mut self, index: usize) {
+        assert!(index < self.len(), "Removal index out of bounds.");
+        let mut obj = self.elem(index);
+        unsafe {
+            dbind_destroy_imp(&mut obj as *mut *mut T as _, T::TYPE.as_ptr());
+            array_delete_ptr(self.inner, index as _, 1, None);
+        }
+    }
+
+    /// Removes and destroys all the elements of the array.
+    pub fn clear(&mut self) {
+        for index in 0..self.len() {
+            let mut obj = self.elem(index);
+            unsafe { dbind_destroy_imp(&mut obj as *mut *mut T as _, T::TYPE.as_ptr()) };
+        }
+        unsafe { array_clear_ptr(self.inner, None) };
+    }
+}
+
+impl<T: DataBind> Default for ArrPt<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DataBind + Clone> Clone for ArrPt<T> {
+    fn clone(&self) -> Self {
+        let mut array = Self::new();
+        for value in self.iter() {
+            array.push(value.clone());
+        }
+        array
+    }
+}
+
+impl<T: DataBind + PartialEq> PartialEq for ArrPt<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: DataBind + Debug> Debug for ArrPt<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: DataBind> Drop for ArrPt<T> {
+    fn drop(&mut self) {
+        self.clear();
+        unsafe { array_destroy_ptr(&mut self.inner, None, Self::type_name().as_ptr()) };
+    }
+}
+
+unsafe impl<T: DataBind> DBindType for ArrPt<T> {
+    fn dbind_type() -> CString {
+        let ty = format!("ArrPt<{}>", T::dbind_type().to_string_lossy());
+        CString::new(ty).unwrap()
+    }
+
+    fn dbind_register_type() -> Result<(), NappguiError> {
+        T::dbind_register_type()
+    }
+}
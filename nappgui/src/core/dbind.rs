@@ -1,10 +1,170 @@
 use std::{
     cell::RefCell,
+    cmp::Ordering,
     collections::HashMap,
     ffi::{CStr, CString},
 };
 
-use crate::error::NappguiError;
+use crate::{
+    core::{NappguiString, Stream},
+    error::{NappguiError, NappguiErrorKind},
+};
+
+/// Types that can be fields of a DataBind object.
+///
+/// # Safety
+/// The DBind type must have the same size and memory layout as the Rust type.
+pub unsafe trait DBindType {
+    /// Name of the type in DBind.
+    fn dbind_type() -> CString;
+
+    /// Registers the type, and the types it depends on, if it is not registered yet.
+    fn dbind_register_type() -> Result<(), NappguiError> {
+        Ok(())
+    }
+}
+
+macro_rules! dbind_basic_type {
+    ($($type:ty => $name:literal),*) => {
+        $(
+            unsafe impl DBindType for $type {
+                fn dbind_type() -> CString {
+                    $name.to_owned()
+                }
+            }
+        )*
+    };
+}
+
+dbind_basic_type!(
+    bool => c"bool_t",
+    i8 => c"int8_t",
+    i16 => c"int16_t",
+    i32 => c"int32_t",
+    i64 => c"int64_t",
+    u8 => c"uint8_t",
+    u16 => c"uint16_t",
+    u32 => c"uint32_t",
+    u64 => c"uint64_t",
+    f32 => c"real32_t",
+    f64 => c"real64_t",
+    NappguiString => c"String"
+);
+
+/// A Rust type registered in DBind, usually implemented with `#[derive(DataBind)]`.
+///
+/// # Safety
+/// The fields registered by dbind_register must match the memory layout of the type, because
+/// NAppGUI reads and writes the objects directly.
+pub unsafe trait DataBind: DBindType + Sized {
+    /// Name of the type registered in DBind.
+    const TYPE: &'static CStr;
+
+    /// Register the type to global dbind so that you can bind it to layout.
+    fn dbind_register() -> Result<(), NappguiError>;
+
+    /// Create an object with the default values of the registered fields.
+    ///
+    /// # Panics
+    /// If the type can't be registered.
+    fn dbind_create() -> Self {
+        dbind_ensure_registered::<Self>();
+        let obj = unsafe { nappgui_sys::dbind_create_imp(Self::TYPE.as_ptr()) };
+        unsafe { dbind_take(obj) }
+    }
+
+    /// Read an object from a binary stream.
+    fn read(stream: &mut Stream) -> Result<Self, NappguiError> {
+        Self::dbind_register_type()?;
+        let obj = unsafe { nappgui_sys::dbind_read_imp(stream.inner, Self::TYPE.as_ptr()) };
+        if obj.is_null() {
+            stream.check(())?;
+            return Err(NappguiError::Internal(NappguiErrorKind::StreamCorrupt));
+        }
+        let obj = unsafe { dbind_take(obj) };
+        stream.check(obj)
+    }
+
+    /// Write an object to a binary stream.
+    fn write(&self, stream: &mut Stream) -> Result<(), NappguiError> {
+        Self::dbind_register_type()?;
+        unsafe { nappgui_sys::dbind_write_imp(stream.inner, self as *const Self as _, Self::TYPE.as_ptr()) };
+        stream.check(())
+    }
+
+    /// Read an object from a stream in JSON format. The JSON keys are the names of the fields.
+    fn from_json(stream: &mut Stream) -> Result<Self, NappguiError> {
+        crate::inet::json::json_read(stream)
+    }
+
+    /// Read an object from a text in JSON format.
+    fn from_json_str(text: &str) -> Result<Self, NappguiError> {
+        let mut stream = Stream::from_block(text.as_bytes(), text.len() as _);
+        Self::from_json(&mut stream)
+    }
+
+    /// Write an object to a stream in JSON format.
+    fn to_json(&self, stream: &mut Stream) -> Result<(), NappguiError> {
+        crate::inet::json::json_write(stream, self)
+    }
+
+    /// Write an object to a text in JSON format.
+    fn to_json_string(&self) -> Result<String, NappguiError> {
+        let mut stream = Stream::memory(1024);
+        self.to_json(&mut stream)?;
+        Ok(String::from_utf8(stream.buffer().to_vec())?)
+    }
+
+    /// Copy an object, including its strings, arrays and nested objects.
+    ///
+    /// # Panics
+    /// If the type can't be registered.
+    fn dbind_copy(&self) -> Self {
+        dbind_ensure_registered::<Self>();
+        let obj = unsafe { nappgui_sys::dbind_copy_imp(self as *const Self as _, Self::TYPE.as_ptr()) };
+        unsafe { dbind_take(obj) }
+    }
+
+    /// Compare two objects field by field, in the order of registration.
+    ///
+    /// # Panics
+    /// If the type can't be registered.
+    fn dbind_cmp(&self, other: &Self) -> Ordering {
+        dbind_ensure_registered::<Self>();
+        let cmp = unsafe {
+            nappgui_sys::dbind_cmp_imp(self as *const Self as _, other as *const Self as _, Self::TYPE.as_ptr())
+        };
+        cmp.cmp(&0)
+    }
+
+    /// Check if two objects are equal field by field.
+    ///
+    /// # Panics
+    /// If the type can't be registered.
+    fn dbind_equ(&self, other: &Self) -> bool {
+        dbind_ensure_registered::<Self>();
+        unsafe {
+            nappgui_sys::dbind_equ_imp(self as *const Self as _, other as *const Self as _, Self::TYPE.as_ptr()) != 0
+        }
+    }
+}
+
+/// DBind only knows the registered types, so they are registered before passing objects to it.
+fn dbind_ensure_registered<T: DataBind>() {
+    if let Err(err) = T::dbind_register_type() {
+        panic!("Unable to register the type {:?}: {}", T::TYPE, err);
+    }
+}
+
+/// Moves an object created by DBind into a Rust value. Only the memory block is released,
+/// the fields are owned by the returned value.
+pub(crate) unsafe fn dbind_take<T: DataBind>(obj: *mut u8) -> T {
+    assert!(!obj.is_null(), "The type {:?} is not registered.", T::TYPE);
+    let value = unsafe { std::ptr::read(obj as *const T) };
+    let mut obj = obj;
+    unsafe { nappgui_sys::heap_free(&mut obj, size_of::<T>() as _, T::TYPE.as_ptr()) };
+    value
+}
 
 /// DBind type.
 pub enum DBind {
@@ -77,6 +237,23 @@ pub struct DBindVariant {
 }
 
 impl DBindEnum {
+    /// Create a dbind to Enum.
+    pub fn new(ty: &CStr) -> Self {
+        Self {
+            ty: ty.to_owned(),
+            variants: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Add a variant to the enum. The alias is the text shown in the controls.
+    pub fn add_variant(&self, name: &CStr, value: i32, alias: &CStr) {
+        self.variants.borrow_mut().push(DBindVariant {
+            name: name.to_owned(),
+            value,
+            alias: alias.to_owned(),
+        });
+    }
+
     /// Registers the enum.
     pub(crate) fn register(&self) -> Result<(), NappguiError> {
         for variant in self.variants.borrow().iter() {
@@ -96,8 +273,8 @@ pub struct DBindStruct {
     pub ty: CString,
     /// Struct size
     pub size: u16,
-    /// Struct fields
-    pub fields: RefCell<HashMap<CString, DBindField>>,
+    /// Struct fields, in the order of declaration.
+    pub fields: RefCell<Vec<DBindField>>,
 }
 
 /// DBind field.
@@ -111,15 +288,15 @@ pub struct DBindField {
     /// Field size in bytes.
     pub(crate) size: u16,
     /// Field range.
-    range: Option<(NappguiNumber, NappguiNumber)>,
+    range: Option<(DBindValue, DBindValue)>,
     /// Field precision.
-    precision: Option<NappguiNumber>,
-    /// Field increment.  
-    increment: Option<NappguiNumber>,
+    precision: Option<DBindValue>,
+    /// Field increment.
+    increment: Option<DBindValue>,
     /// Field suffix.
     suffix: Option<CString>,
     /// Field default value.
-    _default: Option<CString>,
+    default: Option<DBindValue>,
 }
 
 impl DBindStruct {
@@ -127,7 +304,9 @@ impl DBindStruct {
     pub(crate) fn register(&self) -> Result<(), NappguiError> {
         let struct_type = self.ty.as_ptr();
 
-        for (_name, field) in self.fields.borrow().iter() {
+        // The fields are registered in the order of declaration, which is the order of the binary
+        // streams and of the comparisons.
+        for field in self.fields.borrow().iter() {
             let result = unsafe {
                 nappgui_sys::dbind_imp(
                     struct_type,
@@ -142,20 +321,34 @@ impl DBindStruct {
             let _ = convert_dbindst_t_to_nappgui_result(result); // Todo!!
 
             if let Some((min, max)) = &field.range {
-                unsafe { nappgui_sys::dbind_range_imp(struct_type, field.name.as_ptr(), min.as_ptr(), max.as_ptr()) }
+                min.with_ptr(|min| {
+                    max.with_ptr(|max| unsafe {
+                        nappgui_sys::dbind_range_imp(struct_type, field.name.as_ptr(), min, max)
+                    })
+                });
             }
 
             if let Some(precision) = &field.precision {
-                unsafe { nappgui_sys::dbind_precision_imp(struct_type, field.name.as_ptr(), precision.as_ptr()) }
+                precision.with_ptr(|precision| unsafe {
+                    nappgui_sys::dbind_precision_imp(struct_type, field.name.as_ptr(), precision)
+                });
             }
 
             if let Some(increment) = &field.increment {
-                unsafe { nappgui_sys::dbind_increment_imp(struct_type, field.name.as_ptr(), increment.as_ptr()) }
+                increment.with_ptr(|increment| unsafe {
+                    nappgui_sys::dbind_increment_imp(struct_type, field.name.as_ptr(), increment)
+                });
             }
 
             if let Some(suffix) = &field.suffix {
                 unsafe { nappgui_sys::dbind_suffix_imp(struct_type, field.name.as_ptr(), suffix.as_ptr()) }
             }
+
+            if let Some(default) = &field.default {
+                default.with_ptr(|value| unsafe {
+                    nappgui_sys::dbind_default_imp(struct_type, field.name.as_ptr(), value)
+                });
+            }
         }
 
         Ok(())
@@ -166,44 +359,115 @@ impl DBindStruct {
         Self {
             ty: ty.to_owned(),
             size,
-            fields: RefCell::new(HashMap::new()),
+            fields: RefCell::new(Vec::new()),
         }
     }
 
     /// Add a field to struct. If the field exists, then overwrite.
     pub fn add_field(&self, name: &CStr, ty: &CStr, offset: u16, size: u16) {
-        self.fields.borrow_mut().insert(
-            name.to_owned(),
-            DBindField {
-                name: name.to_owned(),
-                ty: ty.to_owned(),
-                offset,
-                size,
-                range: None,
-                precision: None,
-                increment: None,
-                suffix: None,
-                _default: None,
-            },
-        );
+        let field = DBindField {
+            name: name.to_owned(),
+            ty: ty.to_owned(),
+            offset,
+            size,
+            range: None,
+            precision: None,
+            increment: None,
+            suffix: None,
+            default: None,
+        };
+        let mut fields = self.fields.borrow_mut();
+        match fields.iter_mut().find(|current| current.name.as_c_str() == name) {
+            Some(current) => *current = field,
+            None => fields.push(field),
+        }
+    }
+
+    fn with_field(&self, name: &CStr, f: impl FnOnce(&mut DBindField)) {
+        match self.fields.borrow_mut().iter_mut().find(|field| field.name.as_c_str() == name) {
+            Some(field) => f(field),
+            None => panic!("The field {:?} is not registered in {:?}.", name, self.ty),
+        }
+    }
+
+    /// Sets the range of values of a numeric field. The values will be clamped to the range
+    /// when they are edited.
+    ///
+    /// # Panics
+    /// If the field is not added or the type T doesn't have the size of the field.
+    pub fn set_range<T: Copy>(&self, name: &CStr, min: T, max: T) {
+        self.with_field(name, |field| {
+            field.range = Some((DBindValue::new(field, min), DBindValue::new(field, max)));
+        });
+    }
+
+    /// Sets the precision of a numeric field (e.g. 0.01).
+    ///
+    /// # Panics
+    /// If the field is not added or the type T doesn't have the size of the field.
+    pub fn set_precision<T: Copy>(&self, name: &CStr, precision: T) {
+        self.with_field(name, |field| field.precision = Some(DBindValue::new(field, precision)));
+    }
+
+    /// Sets the increment of a numeric field, applied by UpDown controls.
+    ///
+    /// # Panics
+    /// If the field is not added or the type T doesn't have the size of the field.
+    pub fn set_increment<T: Copy>(&self, name: &CStr, increment: T) {
+        self.with_field(name, |field| field.increment = Some(DBindValue::new(field, increment)));
+    }
+
+    /// Sets a suffix that is added to the text of a numeric field (e.g. "kg").
+    pub fn set_suffix(&self, name: &CStr, suffix: &CStr) {
+        self.with_field(name, |field| field.suffix = Some(suffix.to_owned()));
+    }
+
+    /// Sets the default value of a field, used when the object is created.
+    ///
+    /// # Panics
+    /// If the field is not added or the type T doesn't have the size of the field.
+    pub fn set_default<T: Copy>(&self, name: &CStr, default: T) {
+        self.with_field(name, |field| field.default = Some(DBindValue::new(field, default)));
+    }
+
+    /// Sets the default value of a String field.
+    pub fn set_default_text(&self, name: &CStr, default: &CStr) {
+        self.with_field(name, |field| field.default = Some(DBindValue::Text(default.to_owned())));
     }
 }
 
-/// NAppGUI number type.
-#[allow(dead_code)]
-pub(crate) enum NappguiNumber {
-    /// Integer.
-    Integer(NappguiInt),
-    /// Float.
-    Float(NappguiFloat),
+/// Value of a field attribute, stored with the memory layout of the field type.
+pub(crate) enum DBindValue {
+    /// Value of a basic type, up to 8 bytes.
+    Basic(u64),
+    /// Text of a String field.
+    Text(CString),
 }
 
-impl NappguiNumber {
-    /// Converts NappguiNumber to *const u8.
-    pub(crate) fn as_ptr(&self) -> *const u8 {
+impl DBindValue {
+    fn new<T: Copy>(field: &DBindField, value: T) -> Self {
+        let size = size_of::<T>();
+        assert!(
+            size == field.size as usize && size <= size_of::<u64>(),
+            "The value type doesn't match the type of the field {:?}.",
+            field.name
+        );
+        let mut raw = 0u64;
+        unsafe {
+            std::ptr::copy_nonoverlapping(&value as *const T as *const u8, &mut raw as *mut u64 as *mut u8, size)
+        };
+        Self::Basic(raw)
+    }
+
+    /// Calls f with a pointer to the value. The String values are passed as a pointer to
+    /// the C string.
+    fn with_ptr<R>(&self, f: impl FnOnce(*const u8) -> R) -> R {
         match self {
-            NappguiNumber::Integer(value) => value as *const _ as *const u8,
-            NappguiNumber::Float(value) => value as *const _ as *const u8,
+            DBindValue::Basic(raw) => f(raw as *const u64 as *const u8),
+            DBindValue::Text(text) => {
+                let text = text.as_ptr();
+                f(&text as *const *const std::ffi::c_char as *const u8)
+            }
         }
     }
 }
@@ -236,6 +500,18 @@ impl From<DBindStruct> for DBind {
     }
 }
 
+impl From<DBindEnum> for DBind {
+    fn from(value: DBindEnum) -> Self {
+        Self::Enum(value)
+    }
+}
+
+impl From<DBindAlias> for DBind {
+    fn from(value: DBindAlias) -> Self {
+        Self::Alias(value)
+    }
+}
+
 /// Registers DBind.
 pub fn dbind_register<T>(item: T) -> Result<(), NappguiError>
 where
@@ -247,6 +523,11 @@ where
     Ok(())
 }
 
+/// Checks if a type is registered.
+pub fn dbind_registered(ty: &CStr) -> bool {
+    DBIND.with_borrow(|dbind| dbind.contains_key(ty))
+}
+
 /// Unregisters DBind.
 pub fn dbind_unregister(ty: &CStr) -> Result<(), NappguiError> {
     let result = unsafe { nappgui_sys::dbind_unreg_imp(ty.as_ptr()) };
@@ -266,3 +547,17 @@ where
         })
     })
 }
+
+thread_local! {
+    static DBIND_TYPE_NAMES: RefCell<HashMap<CString, &'static CStr>> = RefCell::new(HashMap::new());
+}
+
+/// NAppGUI keeps the type names of the containers without copying them, so they must live
+/// for the entire program.
+pub(crate) fn dbind_type_name(ty: CString) -> &'static CStr {
+    DBIND_TYPE_NAMES.with_borrow_mut(|names| {
+        *names
+            .entry(ty.clone())
+            .or_insert_with(|| Box::leak(ty.into_boxed_c_str()))
+    })
+}
@@ -1,7 +1,9 @@
+pub(crate) mod array;
 pub(crate) mod event;
 pub(crate) mod regex;
 pub(crate) mod stream;
 pub(crate) mod string;
+pub(crate) mod token;
 pub(crate) mod respack;
 
 /// In high-level languages, such as .NET or Javascript, data binding is a technique that allows establishing an automatic 
@@ -17,10 +19,12 @@ pub(crate) mod respack;
 pub(crate) mod dbind;
 
 pub use {
+    array::{ArrPt, ArrSt},
     event::{Event, NappGUIEventParams},
     regex::RegEx,
     stream::Stream,
     string::NappguiString,
+    token::{LexToken, Tokens},
     respack::*,
     dbind::*
 };
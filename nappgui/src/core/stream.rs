@@ -1,8 +1,46 @@
-use std::ffi::CString;
+use std::{
+    ffi::{c_char, CStr, CString},
+    io::{self, BufRead, Read, Write},
+};
 
-use nappgui_sys::{stm_close, stm_from_block, stm_from_file, stm_memory, stm_to_file};
+use nappgui_sys::{
+    stm_append_file, stm_buffer, stm_buffer_size, stm_bytes_readed, stm_bytes_written, stm_close, stm_col, stm_corrupt,
+    stm_file_err, stm_flush, stm_from_block, stm_from_file, stm_get_read_endian, stm_get_read_utf,
+    stm_get_write_endian, stm_get_write_utf, stm_is_memory, stm_memory, stm_pipe, stm_read, stm_read_bool,
+    stm_read_char, stm_read_chars, stm_read_i16, stm_read_i16_tok, stm_read_i32, stm_read_i32_tok, stm_read_i64,
+    stm_read_i64_tok, stm_read_i8, stm_read_i8_tok, stm_read_line, stm_read_r32, stm_read_r32_tok, stm_read_r64,
+    stm_read_r64_tok, stm_read_to_char, stm_read_token, stm_read_trim, stm_read_u16, stm_read_u16_tok, stm_read_u32,
+    stm_read_u32_tok, stm_read_u64, stm_read_u64_tok, stm_read_u8, stm_read_u8_tok, stm_row, stm_set_read_endian,
+    stm_set_read_utf, stm_set_write_endian, stm_set_write_utf, stm_skip, stm_skip_bom, stm_skip_token, stm_state,
+    stm_to_file, stm_token_lexeme, stm_write, stm_write_bool, stm_write_char, stm_write_i16, stm_write_i32,
+    stm_write_i64, stm_write_i8, stm_write_r32, stm_write_r64, stm_write_u16, stm_write_u32, stm_write_u64,
+    stm_write_u8, stm_writef,
+};
 
-use crate::error::NappguiError;
+use crate::{
+    error::NappguiError,
+    types::{Endian, StreamState, Token, Unicode},
+};
+
+macro_rules! write_value {
+    ($(#[$attr:meta])* $name:ident($ty:ty) => $func:ident) => {
+        $(#[$attr])*
+        pub fn $name(&mut self, value: $ty) -> Result<(), NappguiError> {
+            unsafe { $func(self.inner, value as _) };
+            self.check(())
+        }
+    };
+}
+
+macro_rules! read_value {
+    ($(#[$attr:meta])* $name:ident($ty:ty) => $func:ident) => {
+        $(#[$attr])*
+        pub fn $name(&mut self) -> Result<$ty, NappguiError> {
+            let value = unsafe { $func(self.inner) };
+            self.check(value as _)
+        }
+    };
+}
 
 /// A stream is a data flow that runs from a source to a destination. Think of a phone call. We have an
 /// origin (the person who speaks), a destination (the person who listens) and a channel (the line itself).
@@ -45,10 +83,9 @@ impl Stream {
 
     /// Create a stream to read from a file on disk.
     pub fn from_file(pathname: &str) -> Result<Self, NappguiError> {
-        let error = std::ptr::null_mut();
-        let pathname = CString::new(pathname).unwrap();
-        let ptr = unsafe { stm_from_file(pathname.as_ptr(), error) };
-        let error = unsafe { *error };
+        let mut error = 0;
+        let pathname = CString::new(pathname)?;
+        let ptr = unsafe { stm_from_file(pathname.as_ptr(), &mut error) };
         if !ptr.is_null() {
             Ok(Self::new(ptr))
         } else {
@@ -61,10 +98,9 @@ impl Stream {
     /// # Remark
     /// If the file already exists it will be overwritten.
     pub fn to_file(pathname: &str) -> Result<Self, NappguiError> {
-        let error = std::ptr::null_mut();
-        let pathname = CString::new(pathname).unwrap();
-        let ptr = unsafe { stm_to_file(pathname.as_ptr(), error) };
-        let error = unsafe { *error };
+        let mut error = 0;
+        let pathname = CString::new(pathname)?;
+        let ptr = unsafe { stm_to_file(pathname.as_ptr(), &mut error) };
         if !ptr.is_null() {
             Ok(Self::new(ptr))
         } else {
@@ -77,16 +113,411 @@ impl Stream {
     /// # Remark
     /// It will fail if the file does not exist (do not create it). File stream.
     pub fn append_file(pathname: &str) -> Result<Self, NappguiError> {
-        let error = std::ptr::null_mut();
-        let pathname = CString::new(pathname).unwrap();
-        let ptr = unsafe { stm_to_file(pathname.as_ptr(), error) };
-        let error = unsafe { *error };
+        let mut error = 0;
+        let pathname = CString::new(pathname)?;
+        let ptr = unsafe { stm_append_file(pathname.as_ptr(), &mut error) };
         if !ptr.is_null() {
             Ok(Self::new(ptr))
         } else {
             Err(NappguiError::from_ferror_t(error))
         }
     }
+
+    pub(crate) fn check<T>(&self, value: T) -> Result<T, NappguiError> {
+        match self.state() {
+            StreamState::Ok => Ok(value),
+            state => Err(NappguiError::from_sstate_t(state as _)),
+        }
+    }
+
+    fn check_text(&self, text: *const c_char) -> Result<String, NappguiError> {
+        if text.is_null() {
+            return match self.state() {
+                StreamState::Ok => Err(NappguiError::from_sstate_t(StreamState::End as _)),
+                state => Err(NappguiError::from_sstate_t(state as _)),
+            };
+        }
+        let text = unsafe { CStr::from_ptr(text) };
+        self.check(text.to_string_lossy().into_owned())
+    }
+
+    /// Gets the current byte order used when writing binary data.
+    pub fn write_endian(&self) -> Endian {
+        Endian::try_from(unsafe { stm_get_write_endian(self.inner) }).unwrap()
+    }
+
+    /// Gets the current byte order used when reading binary data.
+    pub fn read_endian(&self) -> Endian {
+        Endian::try_from(unsafe { stm_get_read_endian(self.inner) }).unwrap()
+    }
+
+    /// Set the byte order used when writing binary data.
+    ///
+    /// # Remark
+    /// By default it is little endian, except in sockets that is big endian.
+    pub fn set_write_endian(&mut self, endian: Endian) {
+        unsafe { stm_set_write_endian(self.inner, endian as _) }
+    }
+
+    /// Set the byte order used when reading binary data.
+    pub fn set_read_endian(&mut self, endian: Endian) {
+        unsafe { stm_set_read_endian(self.inner, endian as _) }
+    }
+
+    /// Gets the current encoding used when writing text.
+    pub fn write_utf(&self) -> Unicode {
+        Unicode::try_from(unsafe { stm_get_write_utf(self.inner) }).unwrap()
+    }
+
+    /// Gets the current encoding used when reading text.
+    pub fn read_utf(&self) -> Unicode {
+        Unicode::try_from(unsafe { stm_get_read_utf(self.inner) }).unwrap()
+    }
+
+    /// Set the encoding used when writing text. By default UTF-8.
+    pub fn set_write_utf(&mut self, format: Unicode) {
+        unsafe { stm_set_write_utf(self.inner, format as _) }
+    }
+
+    /// Set the encoding used when reading text. By default UTF-8.
+    pub fn set_read_utf(&mut self, format: Unicode) {
+        unsafe { stm_set_read_utf(self.inner, format as _) }
+    }
+
+    /// Check if it is a memory stream.
+    pub fn is_memory(&self) -> bool {
+        unsafe { stm_is_memory(self.inner) != 0 }
+    }
+
+    /// Gets the total number of bytes written in the stream since its creation.
+    pub fn bytes_written(&self) -> u64 {
+        unsafe { stm_bytes_written(self.inner) }
+    }
+
+    /// Gets the total number of bytes read from the stream since its creation.
+    pub fn bytes_read(&self) -> u64 {
+        unsafe { stm_bytes_readed(self.inner) }
+    }
+
+    /// Gets the column number of the last character read in a text stream.
+    pub fn col(&self) -> u32 {
+        unsafe { stm_col(self.inner) }
+    }
+
+    /// Gets the row number of the last character read in a text stream.
+    pub fn row(&self) -> u32 {
+        unsafe { stm_row(self.inner) }
+    }
+
+    /// Gets the current state of the stream.
+    pub fn state(&self) -> StreamState {
+        StreamState::try_from(unsafe { stm_state(self.inner) }).unwrap()
+    }
+
+    /// Gets the error of a file stream, if any.
+    pub fn file_error(&self) -> Option<NappguiError> {
+        let error = unsafe { stm_file_err(self.inner) };
+        if error == nappgui_sys::_ferror_t_ekFOK {
+            None
+        } else {
+            Some(NappguiError::from_ferror_t(error))
+        }
+    }
+
+    /// Set the stream state to corrupt.
+    ///
+    /// # Remark
+    /// Useful to stop reading when the data does not have the expected format.
+    pub fn corrupt(&mut self) {
+        unsafe { stm_corrupt(self.inner) }
+    }
+
+    /// Gets the content of the internal buffer, the bytes pending to be read.
+    ///
+    /// # Remark
+    /// Only valid for memory streams.
+    pub fn buffer(&self) -> &[u8] {
+        let size = unsafe { stm_buffer_size(self.inner) } as usize;
+        if size == 0 {
+            return &[];
+        }
+        let ptr = unsafe { stm_buffer(self.inner) };
+        unsafe { std::slice::from_raw_parts(ptr, size) }
+    }
+
+    /// Write bytes in the stream.
+    pub fn write_bytes(&mut self, data: &[u8]) -> Result<(), NappguiError> {
+        unsafe { stm_write(self.inner, data.as_ptr(), data.len() as _) };
+        self.check(())
+    }
+
+    /// Write a Unicode character in the stream, using the current write encoding.
+    pub fn write_char(&mut self, ch: char) -> Result<(), NappguiError> {
+        unsafe { stm_write_char(self.inner, ch as _) };
+        self.check(())
+    }
+
+    /// Write a text in the stream, using the current write encoding.
+    pub fn write_str(&mut self, text: &str) -> Result<(), NappguiError> {
+        let text = CString::new(text)?;
+        unsafe { stm_writef(self.inner, text.as_ptr()) };
+        self.check(())
+    }
+
+    write_value!(
+        /// Write a bool value in the stream.
+        write_bool(bool) => stm_write_bool
+    );
+    write_value!(
+        /// Write an i8 value in the stream.
+        write_i8(i8) => stm_write_i8
+    );
+    write_value!(
+        /// Write an i16 value in the stream, using the current write byte order.
+        write_i16(i16) => stm_write_i16
+    );
+    write_value!(
+        /// Write an i32 value in the stream, using the current write byte order.
+        write_i32(i32) => stm_write_i32
+    );
+    write_value!(
+        /// Write an i64 value in the stream, using the current write byte order.
+        write_i64(i64) => stm_write_i64
+    );
+    write_value!(
+        /// Write an u8 value in the stream.
+        write_u8(u8) => stm_write_u8
+    );
+    write_value!(
+        /// Write an u16 value in the stream, using the current write byte order.
+        write_u16(u16) => stm_write_u16
+    );
+    write_value!(
+        /// Write an u32 value in the stream, using the current write byte order.
+        write_u32(u32) => stm_write_u32
+    );
+    write_value!(
+        /// Write an u64 value in the stream, using the current write byte order.
+        write_u64(u64) => stm_write_u64
+    );
+    write_value!(
+        /// Write a f32 value in the stream, using the current write byte order.
+        write_f32(f32) => stm_write_r32
+    );
+    write_value!(
+        /// Write a f64 value in the stream, using the current write byte order.
+        write_f64(f64) => stm_write_r64
+    );
+
+    /// Read bytes from the stream. Returns the number of bytes read.
+    pub fn read_bytes(&mut self, data: &mut [u8]) -> Result<usize, NappguiError> {
+        let size = unsafe { stm_read(self.inner, data.as_mut_ptr(), data.len() as _) };
+        self.check(size as usize)
+    }
+
+    /// Read a Unicode character from the stream, using the current read encoding.
+    pub fn read_char(&mut self) -> Result<char, NappguiError> {
+        let codepoint = unsafe { stm_read_char(self.inner) };
+        let ch = self.check(codepoint)?;
+        char::from_u32(ch).ok_or(NappguiError::from_sstate_t(StreamState::Corrupt as _))
+    }
+
+    /// Read several characters from the stream.
+    pub fn read_chars(&mut self, n: u32) -> Result<String, NappguiError> {
+        let text = unsafe { stm_read_chars(self.inner, n) };
+        self.check_text(text)
+    }
+
+    /// Read a line of text, without the end of line characters.
+    pub fn read_line(&mut self) -> Result<String, NappguiError> {
+        let text = unsafe { stm_read_line(self.inner) };
+        self.check_text(text)
+    }
+
+    /// Read characters until a given one is found. The end character is not included.
+    pub fn read_to_char(&mut self, endchar: char) -> Result<String, NappguiError> {
+        let text = unsafe { stm_read_to_char(self.inner, endchar as _) };
+        self.check_text(text)
+    }
+
+    /// Read the next text block delimited by blank spaces.
+    pub fn read_trim(&mut self) -> Result<String, NappguiError> {
+        let text = unsafe { stm_read_trim(self.inner) };
+        self.check_text(text)
+    }
+
+    /// Read the next lexical token of the stream.
+    ///
+    /// # Remark
+    /// Use token_lexeme to get the text of the token. At the end of the stream Token::Eof is returned.
+    pub fn read_token(&mut self) -> Token {
+        Token::try_from(unsafe { stm_read_token(self.inner) }).unwrap()
+    }
+
+    /// Gets the text of the last token read.
+    pub fn token_lexeme(&self) -> String {
+        let mut size = 0;
+        let text = unsafe { stm_token_lexeme(self.inner, &mut size) };
+        if text.is_null() {
+            return String::new();
+        }
+        let bytes = unsafe { std::slice::from_raw_parts(text as *const u8, size as usize) };
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+
+    read_value!(
+        /// Read a text token and convert it to i8.
+        read_i8_tok(i8) => stm_read_i8_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to i16.
+        read_i16_tok(i16) => stm_read_i16_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to i32.
+        read_i32_tok(i32) => stm_read_i32_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to i64.
+        read_i64_tok(i64) => stm_read_i64_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to u8.
+        read_u8_tok(u8) => stm_read_u8_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to u16.
+        read_u16_tok(u16) => stm_read_u16_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to u32.
+        read_u32_tok(u32) => stm_read_u32_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to u64.
+        read_u64_tok(u64) => stm_read_u64_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to f32.
+        read_f32_tok(f32) => stm_read_r32_tok
+    );
+    read_value!(
+        /// Read a text token and convert it to f64.
+        read_f64_tok(f64) => stm_read_r64_tok
+    );
+
+    /// Read a bool value from the stream.
+    pub fn read_bool(&mut self) -> Result<bool, NappguiError> {
+        let value = unsafe { stm_read_bool(self.inner) };
+        self.check(value != 0)
+    }
+
+    read_value!(
+        /// Read an i8 value from the stream.
+        read_i8(i8) => stm_read_i8
+    );
+    read_value!(
+        /// Read an i16 value from the stream, using the current read byte order.
+        read_i16(i16) => stm_read_i16
+    );
+    read_value!(
+        /// Read an i32 value from the stream, using the current read byte order.
+        read_i32(i32) => stm_read_i32
+    );
+    read_value!(
+        /// Read an i64 value from the stream, using the current read byte order.
+        read_i64(i64) => stm_read_i64
+    );
+    read_value!(
+        /// Read an u8 value from the stream.
+        read_u8(u8) => stm_read_u8
+    );
+    read_value!(
+        /// Read an u16 value from the stream, using the current read byte order.
+        read_u16(u16) => stm_read_u16
+    );
+    read_value!(
+        /// Read an u32 value from the stream, using the current read byte order.
+        read_u32(u32) => stm_read_u32
+    );
+    read_value!(
+        /// Read an u64 value from the stream, using the current read byte order.
+        read_u64(u64) => stm_read_u64
+    );
+    read_value!(
+        /// Read a f32 value from the stream, using the current read byte order.
+        read_f32(f32) => stm_read_r32
+    );
+    read_value!(
+        /// Read a f64 value from the stream, using the current read byte order.
+        read_f64(f64) => stm_read_r64
+    );
+
+    /// Skip bytes of the stream.
+    pub fn skip(&mut self, size: u32) {
+        unsafe { stm_skip(self.inner, size) }
+    }
+
+    /// Skip the Byte Order Mark (BOM) at the beginning of some UTF-8 text files.
+    pub fn skip_bom(&mut self) {
+        unsafe { stm_skip_bom(self.inner) }
+    }
+
+    /// Skip the next token of the stream. The stream will be corrupt if it is not the expected one.
+    pub fn skip_token(&mut self, token: Token) -> Result<(), NappguiError> {
+        unsafe { stm_skip_token(self.inner, token as _) };
+        self.check(())
+    }
+
+    /// Write the data of the cache buffer to the channel.
+    pub fn flush(&mut self) {
+        unsafe { stm_flush(self.inner) }
+    }
+
+    /// Pipe data from one stream to another.
+    pub fn pipe(&mut self, to: &mut Stream, n: u32) -> Result<(), NappguiError> {
+        unsafe { stm_pipe(self.inner, to.inner, n) };
+        self.check(())?;
+        to.check(())
+    }
+}
+
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let size = buf.len().min(u32::MAX as usize);
+        let size = unsafe { stm_read(self.inner, buf.as_mut_ptr(), size as _) };
+        match self.state() {
+            StreamState::Ok | StreamState::End => Ok(size as usize),
+            state => Err(io::Error::other(NappguiError::from_sstate_t(state as _))),
+        }
+    }
+}
+
+/// Only available in memory streams. Wrap other streams in a `std::io::BufReader`.
+impl BufRead for Stream {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if !self.is_memory() {
+            return Err(io::Error::new(io::ErrorKind::Unsupported, "Not a memory stream"));
+        }
+        Ok(self.buffer())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.skip(amt as _);
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let size = buf.len().min(u32::MAX as usize);
+        unsafe { stm_write(self.inner, buf.as_ptr(), size as _) };
+        self.check(size).map_err(io::Error::other)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Stream::flush(self);
+        self.check(()).map_err(io::Error::other)
+    }
 }
 
 impl Drop for Stream {
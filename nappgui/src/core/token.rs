@@ -0,0 +1,95 @@
+use nappgui_sys::{stm_token_col, stm_token_comments, stm_token_escapes, stm_token_row, stm_token_spaces};
+
+use crate::{core::Stream, types::Token};
+
+/// A lexical token read from a stream, along with its position in the source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexToken {
+    /// Kind of token.
+    pub token: Token,
+    /// Text of the token.
+    pub lexeme: String,
+    /// Row (line number) where the token starts.
+    pub row: u32,
+    /// Column where the token starts.
+    pub col: u32,
+}
+
+/// Iterator over the lexical tokens of a stream. See Stream::tokens.
+///
+/// # Remark
+/// The iteration ends at the end of the stream. If the data is corrupt, a Token::Corrupt item is
+/// returned and the iteration ends.
+pub struct Tokens<'a> {
+    stream: &'a mut Stream,
+    finished: bool,
+}
+
+impl Iterator for Tokens<'_> {
+    type Item = LexToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let token = self.stream.read_token();
+        match token {
+            Token::Eof => {
+                self.finished = true;
+                return None;
+            }
+            Token::Corrupt => self.finished = true,
+            _ => {}
+        }
+        Some(LexToken {
+            token,
+            lexeme: self.stream.token_lexeme(),
+            row: self.stream.token_row(),
+            col: self.stream.token_col(),
+        })
+    }
+}
+
+impl std::iter::FusedIterator for Tokens<'_> {}
+
+impl Stream {
+    /// Gets an iterator over the lexical tokens of the stream.
+    ///
+    /// # Remark
+    /// The lexical analyzer recognizes the tokens of the C language (identifiers, numbers, strings,
+    /// comments and symbols). By default, spaces and comments are ignored.
+    pub fn tokens(&mut self) -> Tokens<'_> {
+        Tokens {
+            stream: self,
+            finished: false,
+        }
+    }
+
+    /// Gets the row of the last token read.
+    pub fn token_row(&self) -> u32 {
+        unsafe { stm_token_row(self.inner) }
+    }
+
+    /// Gets the column of the last token read.
+    pub fn token_col(&self) -> u32 {
+        unsafe { stm_token_col(self.inner) }
+    }
+
+    /// Activate or deactivate the processing of escape sequences when reading Token::String tokens.
+    ///
+    /// # Remark
+    /// If active, escape sequences such as `\n` are replaced by the characters they represent.
+    pub fn set_token_escapes(&mut self, active: bool) {
+        unsafe { stm_token_escapes(self.inner, active as _) }
+    }
+
+    /// Activate or deactivate Token::Space tokens. By default, blank spaces are ignored.
+    pub fn set_token_spaces(&mut self, active: bool) {
+        unsafe { stm_token_spaces(self.inner, active as _) }
+    }
+
+    /// Activate or deactivate comment tokens. By default, comments are ignored.
+    pub fn set_token_comments(&mut self, active: bool) {
+        unsafe { stm_token_comments(self.inner, active as _) }
+    }
+}
@@ -1,8 +1,23 @@
-use nappgui_sys::{dctx_bitmap, dctx_image, draw_antialias, draw_clear, draw_matrixf};
+use std::ffi::CString;
 
-use crate::types::PixFormat;
+use nappgui_sys::{
+    dctx_bitmap, dctx_image, draw_antialias, draw_arc, draw_bezier, draw_box2dd, draw_box2df, draw_cir2dd, draw_cir2df,
+    draw_circle, draw_clear, draw_ellipse, draw_fill_color, draw_fill_linear, draw_fill_matrix, draw_fill_wrap,
+    draw_font, draw_image, draw_image_align, draw_image_frame, draw_line, draw_line_cap, draw_line_color,
+    draw_line_dash, draw_line_fill, draw_line_join, draw_line_width, draw_matrix_cartesiand, draw_matrix_cartesianf,
+    draw_matrixd, draw_matrixf, draw_obb2dd, draw_obb2df, draw_pol2dd, draw_pol2df, draw_polygon, draw_polyline,
+    draw_r2df, draw_rect, draw_rndrect, draw_seg2dd, draw_seg2df, draw_text, draw_text_align, draw_text_color,
+    draw_text_extents, draw_text_halign, draw_text_path, draw_text_trim, draw_text_width, draw_tri2dd, draw_tri2df,
+    draw_v2dd, draw_v2df,
+};
 
-use super::{Color, Image, Transformation2D};
+use crate::geom_2d::{
+    Box2D, Box2Dd, Circle2D, Circle2Dd, OBB2Dd, Point2Dd, Polygon2D, Polygon2Dd, Rect2D, Segment2D, Segment2Dd,
+    Transformation2Dd, Triangle2D, Triangle2Dd, OBB2D,
+};
+use crate::types::{Align, DrawOp, Ellipsis, FillWrap, LineCap, LineJoin, PixFormat};
+
+use super::{Color, Font, Image, Point2D, Transformation2D};
 
 /// Drawing context.
 pub struct DCtx {
@@ -41,17 +56,23 @@ impl DCtx {
     ///
     /// # Remark
     /// The origin of coordinates is in the upper left corner. The Y axis increases down.
-    pub fn matrix(&self, t2d: &[Transformation2D]) {
-        unsafe {
-            draw_matrixf(self.inner, t2d.as_ptr() as _);
-        }
+    pub fn matrix(&self, t2d: &Transformation2D) {
+        unsafe { draw_matrixf(self.inner, t2d.as_raw()) }
     }
 
     /// Set the reference system in Cartesian coordinates.
-    pub fn matrix_cartesian(&self, t2d: &[Transformation2D]) {
-        unsafe {
-            draw_matrixf(self.inner, t2d.as_ptr() as _);
-        }
+    pub fn matrix_cartesian(&self, t2d: &Transformation2D) {
+        unsafe { draw_matrix_cartesianf(self.inner, t2d.as_raw()) }
+    }
+
+    /// Set the context reference system in double precision.
+    pub fn matrixd(&self, t2d: &Transformation2Dd) {
+        unsafe { draw_matrixd(self.inner, t2d.as_raw()) }
+    }
+
+    /// Set the reference system in Cartesian coordinates in double precision.
+    pub fn matrix_cartesiand(&self, t2d: &Transformation2Dd) {
+        unsafe { draw_matrix_cartesiand(self.inner, t2d.as_raw()) }
     }
 
     /// Enable or disable antialiasing.
@@ -64,4 +85,306 @@ impl DCtx {
             draw_antialias(self.inner, enable as _);
         }
     }
+
+    /// Draw a line.
+    pub fn line(&self, x0: f32, y0: f32, x1: f32, y1: f32) {
+        unsafe { draw_line(self.inner, x0, y0, x1, y1) }
+    }
+
+    /// Draw several joined lines.
+    ///
+    /// # Remark
+    /// If closed is true, the last point will be joined with the first one.
+    pub fn polyline(&self, closed: bool, points: &[Point2D]) {
+        unsafe { draw_polyline(self.inner, closed as _, points.as_ptr() as _, points.len() as _) }
+    }
+
+    /// Draw an arc (circle segment).
+    ///
+    /// # Remark
+    /// start and sweep are expressed in radians. Positive sweep values go clockwise.
+    pub fn arc(&self, x: f32, y: f32, radius: f32, start: f32, sweep: f32) {
+        unsafe { draw_arc(self.inner, x, y, radius, start, sweep) }
+    }
+
+    /// Draw a cubic Bézier curve between (x0, y0) and (x3, y3), using (x1, y1) and (x2, y2) as control points.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bezier(&self, x0: f32, y0: f32, x1: f32, y1: f32, x2: f32, y2: f32, x3: f32, y3: f32) {
+        unsafe { draw_bezier(self.inner, x0, y0, x1, y1, x2, y2, x3, y3) }
+    }
+
+    /// Set the color of the lines.
+    pub fn line_color(&self, color: Color) {
+        unsafe { draw_line_color(self.inner, color.inner) }
+    }
+
+    /// Set the current fill pattern for line drawing.
+    ///
+    /// # Remark
+    /// The lines will be drawn with the same pattern (color, gradient) used to fill the figures.
+    pub fn line_fill(&self) {
+        unsafe { draw_line_fill(self.inner) }
+    }
+
+    /// Set the line width.
+    pub fn line_width(&self, width: f32) {
+        unsafe { draw_line_width(self.inner, width) }
+    }
+
+    /// Set the style of the line ends.
+    pub fn line_cap(&self, cap: LineCap) {
+        unsafe { draw_line_cap(self.inner, cap as _) }
+    }
+
+    /// Set the style of the junctions between line segments.
+    pub fn line_join(&self, join: LineJoin) {
+        unsafe { draw_line_join(self.inner, join as _) }
+    }
+
+    /// Set a pattern for dashed lines.
+    ///
+    /// # Remark
+    /// The pattern alternates the length of the strokes and the spaces, relative to the line width.
+    /// An empty pattern will restore the solid line.
+    pub fn line_dash(&self, pattern: &[f32]) {
+        let ptr = if pattern.is_empty() {
+            std::ptr::null()
+        } else {
+            pattern.as_ptr()
+        };
+        unsafe { draw_line_dash(self.inner, ptr, pattern.len() as _) }
+    }
+
+    /// Draw a rectangle.
+    pub fn rect(&self, op: DrawOp, x: f32, y: f32, width: f32, height: f32) {
+        unsafe { draw_rect(self.inner, op as _, x, y, width, height) }
+    }
+
+    /// Draw a rectangle with rounded corners.
+    pub fn round_rect(&self, op: DrawOp, x: f32, y: f32, width: f32, height: f32, radius: f32) {
+        unsafe { draw_rndrect(self.inner, op as _, x, y, width, height, radius) }
+    }
+
+    /// Draw a circle.
+    pub fn circle(&self, op: DrawOp, x: f32, y: f32, radius: f32) {
+        unsafe { draw_circle(self.inner, op as _, x, y, radius) }
+    }
+
+    /// Draw an ellipse.
+    pub fn ellipse(&self, op: DrawOp, x: f32, y: f32, radx: f32, rady: f32) {
+        unsafe { draw_ellipse(self.inner, op as _, x, y, radx, rady) }
+    }
+
+    /// Draw a polygon.
+    pub fn polygon(&self, op: DrawOp, points: &[Point2D]) {
+        unsafe { draw_polygon(self.inner, op as _, points.as_ptr() as _, points.len() as _) }
+    }
+
+    /// Set a solid color for the fill of the figures.
+    pub fn fill_color(&self, color: Color) {
+        unsafe { draw_fill_color(self.inner, color.inner) }
+    }
+
+    /// Set a linear gradient for the fill of the figures.
+    ///
+    /// # Remark
+    /// colors and stops must have the same length. Each stop is the position of the color along the
+    /// gradient vector, from 0 (start) to 1 (end). The gradient vector is expressed in the coordinates
+    /// of the current reference system, see fill_matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if colors and stops have different lengths or are empty.
+    pub fn fill_linear(&self, colors: &[Color], stops: &[f32], start: Point2D, end: Point2D) {
+        assert!(!colors.is_empty());
+        assert_eq!(colors.len(), stops.len());
+
+        unsafe {
+            draw_fill_linear(
+                self.inner,
+                colors.as_ptr() as _,
+                stops.as_ptr(),
+                colors.len() as _,
+                start.x,
+                start.y,
+                end.x,
+                end.y,
+            )
+        }
+    }
+
+    /// Set the transformation matrix of the fill pattern.
+    ///
+    /// # Remark
+    /// By default the gradient is positioned in the coordinates of the current matrix. Use this function
+    /// to move, rotate or scale the gradient independently of the figures.
+    pub fn fill_matrix(&self, t2d: &Transformation2D) {
+        unsafe { draw_fill_matrix(self.inner, t2d as *const Transformation2D as _) }
+    }
+
+    /// Set the behavior of the gradient outside its limits.
+    pub fn fill_wrap(&self, wrap: FillWrap) {
+        unsafe { draw_fill_wrap(self.inner, wrap as _) }
+    }
+
+    /// Draw a text block.
+    ///
+    /// # Remark
+    /// The text will be drawn with the current font, color and alignment of the context.
+    pub fn text(&self, text: &str, x: f32, y: f32) {
+        let text = CString::new(text).unwrap();
+        unsafe { draw_text(self.inner, text.as_ptr(), x, y) }
+    }
+
+    /// Draw text as a geometric figure.
+    ///
+    /// # Remark
+    /// Allows using gradients or outlines on the text, since it is treated as any other figure.
+    pub fn text_path(&self, op: DrawOp, text: &str, x: f32, y: f32) {
+        let text = CString::new(text).unwrap();
+        unsafe { draw_text_path(self.inner, op as _, text.as_ptr(), x, y) }
+    }
+
+    /// Set the font for drawing texts.
+    ///
+    /// # Remark
+    /// The context keeps an internal copy of the font.
+    pub fn font(&self, font: &Font) {
+        unsafe { draw_font(self.inner, font.as_ptr()) }
+    }
+
+    /// Set the text color.
+    pub fn text_color(&self, color: Color) {
+        unsafe { draw_text_color(self.inner, color.inner) }
+    }
+
+    /// Set the maximum width of the text blocks.
+    ///
+    /// # Remark
+    /// If the text is wider, it will be split into several lines or trimmed, depending on text_trim.
+    /// A negative value removes the limit.
+    pub fn text_width(&self, width: f32) {
+        unsafe { draw_text_width(self.inner, width) }
+    }
+
+    /// Set how the text will be trimmed when it is wider than text_width.
+    pub fn text_trim(&self, ellipsis: Ellipsis) {
+        unsafe { draw_text_trim(self.inner, ellipsis as _) }
+    }
+
+    /// Set the alignment of the text block with respect to the point of insertion.
+    pub fn text_align(&self, halign: Align, valign: Align) {
+        unsafe { draw_text_align(self.inner, halign as _, valign as _) }
+    }
+
+    /// Set the internal horizontal alignment of the lines of a multi-line text block.
+    pub fn text_halign(&self, halign: Align) {
+        unsafe { draw_text_halign(self.inner, halign as _) }
+    }
+
+    /// Gets the size in pixels of a text block, based on the current font of the context.
+    ///
+    /// # Remark
+    /// refwidth is the maximum width of the block. If it is negative, the text will only be split by
+    /// its new line characters. Returns (width, height).
+    pub fn text_extents(&self, text: &str, refwidth: f32) -> (f32, f32) {
+        let text = CString::new(text).unwrap();
+        let mut width = 0f32;
+        let mut height = 0f32;
+        unsafe { draw_text_extents(self.inner, text.as_ptr(), refwidth, &mut width, &mut height) };
+        (width, height)
+    }
+
+    /// Draw an image.
+    pub fn draw_image(&self, image: &Image, x: f32, y: f32) {
+        unsafe { draw_image(self.inner, image.as_ptr(), x, y) }
+    }
+
+    /// Draw a specific frame of an animated image.
+    ///
+    /// # Remark
+    /// Only gif format supports animations. See Image::num_frames.
+    pub fn draw_image_frame(&self, image: &Image, frame: u32, x: f32, y: f32) {
+        unsafe { draw_image_frame(self.inner, image.as_ptr(), frame, x, y) }
+    }
+
+    /// Set the alignment of the image with respect to the point of insertion.
+    pub fn image_align(&self, halign: Align, valign: Align) {
+        unsafe { draw_image_align(self.inner, halign as _, valign as _) }
+    }
+
+    /// Draw a point as a circle of the given radius.
+    pub fn point2d(&self, op: DrawOp, point: &Point2D, radius: f32) {
+        unsafe { draw_v2df(self.inner, op as _, point.as_raw(), radius) }
+    }
+
+    /// Draw a segment.
+    pub fn segment2d(&self, segment: &Segment2D) {
+        unsafe { draw_seg2df(self.inner, segment.as_raw()) }
+    }
+
+    /// Draw a rectangle.
+    pub fn rect2d(&self, op: DrawOp, rect: &Rect2D) {
+        unsafe { draw_r2df(self.inner, op as _, rect as *const Rect2D as _) }
+    }
+
+    /// Draw a circle.
+    pub fn circle2d(&self, op: DrawOp, circle: &Circle2D) {
+        unsafe { draw_cir2df(self.inner, op as _, circle.as_raw()) }
+    }
+
+    /// Draw an axis aligned box.
+    pub fn box2d(&self, op: DrawOp, bbox: &Box2D) {
+        unsafe { draw_box2df(self.inner, op as _, bbox.as_raw()) }
+    }
+
+    /// Draw an oriented box.
+    pub fn obb2d(&self, op: DrawOp, obb: &OBB2D) {
+        unsafe { draw_obb2df(self.inner, op as _, obb.as_raw()) }
+    }
+
+    /// Draw a triangle.
+    pub fn triangle2d(&self, op: DrawOp, triangle: &Triangle2D) {
+        unsafe { draw_tri2df(self.inner, op as _, triangle.as_raw()) }
+    }
+
+    /// Draw a polygon.
+    pub fn polygon2d(&self, op: DrawOp, polygon: &Polygon2D) {
+        unsafe { draw_pol2df(self.inner, op as _, polygon.as_raw()) }
+    }
+
+    /// Draw a point as a circle of the given radius in double precision.
+    pub fn point2dd(&self, op: DrawOp, point: &Point2Dd, radius: f64) {
+        unsafe { draw_v2dd(self.inner, op as _, point.as_raw(), radius) }
+    }
+
+    /// Draw a segment in double precision.
+    pub fn segment2dd(&self, segment: &Segment2Dd) {
+        unsafe { draw_seg2dd(self.inner, segment.as_raw()) }
+    }
+
+    /// Draw a circle in double precision.
+    pub fn circle2dd(&self, op: DrawOp, circle: &Circle2Dd) {
+        unsafe { draw_cir2dd(self.inner, op as _, circle.as_raw()) }
+    }
+
+    /// Draw an axis aligned box in double precision.
+    pub fn box2dd(&self, op: DrawOp, bbox: &Box2Dd) {
+        unsafe { draw_box2dd(self.inner, op as _, bbox.as_raw()) }
+    }
+
+    /// Draw an oriented box in double precision.
+    pub fn obb2dd(&self, op: DrawOp, obb: &OBB2Dd) {
+        unsafe { draw_obb2dd(self.inner, op as _, obb.as_raw()) }
+    }
+
+    /// Draw a triangle in double precision.
+    pub fn triangle2dd(&self, op: DrawOp, triangle: &Triangle2Dd) {
+        unsafe { draw_tri2dd(self.inner, op as _, triangle.as_raw()) }
+    }
+
+    /// Draw a polygon in double precision.
+    pub fn polygon2dd(&self, op: DrawOp, polygon: &Polygon2Dd) {
+        unsafe { draw_pol2dd(self.inner, op as _, polygon.as_raw()) }
+    }
 }
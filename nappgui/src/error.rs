@@ -19,6 +19,24 @@ pub enum NappguiError {
     ParseIntError(std::num::ParseIntError),
     /// Unknown error
     Unknown(String),
+    /// JSON parsing error
+    Json {
+        /// Messages of the parser.
+        message: String,
+        /// Row of the stream where the parser stopped.
+        row: u32,
+        /// Column of the stream where the parser stopped.
+        col: u32,
+    },
+    /// XML user interface error
+    Xml {
+        /// Description of the error.
+        message: String,
+        /// Row of the XML where the error was found.
+        row: u32,
+        /// Column of the XML where the error was found.
+        col: u32,
+    },
 }
 
 /// Error kinds enum for `NappguiError`
@@ -72,7 +90,13 @@ pub enum NappguiErrorKind {
     /// Server error.
     ServerError,
     /// Not implemented.
-    NotImplemented
+    NotImplemented,
+    /// The end of the stream has been reached.
+    StreamEnd,
+    /// The stream data is corrupt.
+    StreamCorrupt,
+    /// The stream is broken, due to an i/o or network error.
+    StreamBroken,
 }
 
 impl NappguiError {
@@ -105,6 +129,16 @@ impl NappguiError {
         NappguiError::Internal(err_kind)
     }
 
+    pub(crate) fn from_sstate_t(state: i32) -> NappguiError {
+        let err_kind = match state {
+            nappgui_sys::_sstate_t_ekSTEND => NappguiErrorKind::StreamEnd,
+            nappgui_sys::_sstate_t_ekSTCORRUPT => NappguiErrorKind::StreamCorrupt,
+            nappgui_sys::_sstate_t_ekSTBROKEN => NappguiErrorKind::StreamBroken,
+            _ => NappguiErrorKind::UndefinedError,
+        };
+        NappguiError::Internal(err_kind)
+    }
+
     pub(crate) fn from_ierror_t(err: i32) -> NappguiError {
         let err_kind = match err {
             nappgui_sys::_ierror_t_ekINONET => NappguiErrorKind::NoNetwork,
@@ -147,6 +181,12 @@ impl fmt::Display for NappguiError {
                 write!(f, "An int parsing error occurred {:?}", err)
             }
             NappguiError::Unknown(ref err) => write!(f, "An unknown error occurred {:?}", err),
+            NappguiError::Json { ref message, row, col } => {
+                write!(f, "A JSON error occurred at row {}, column {}: {}", row, col, message)
+            }
+            NappguiError::Xml { ref message, row, col } => {
+                write!(f, "An XML error occurred at row {}, column {}: {}", row, col, message)
+            }
         }
     }
 }
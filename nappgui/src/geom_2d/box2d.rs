@@ -0,0 +1,134 @@
+use nappgui_sys::{
+    box2d_add_circled, box2d_add_circlef, box2d_addd, box2d_addf, box2d_addnd, box2d_addnf, box2d_aread, box2d_areaf,
+    box2d_centerd, box2d_centerf, box2d_from_pointsd, box2d_from_pointsf, box2d_is_nulld, box2d_is_nullf, box2d_merged,
+    box2d_mergef, box2d_segmentsd, box2d_segmentsf, kBOX2D_NULLd, kBOX2D_NULLf,
+};
+
+use super::{Circle2D, Circle2Dd, Point2D, Point2Dd, Segment2D, Segment2Dd};
+
+macro_rules! bounding_box {
+    (
+        $(#[$attr:meta])*
+        $name:ident($real:ty, $point:ident, $circle:ident, $segment:ident, $raw:ty),
+        $null:ident, $from_points:ident, $center:ident, $add:ident, $addn:ident, $add_circle:ident,
+        $merge:ident, $segments:ident, $area:ident, $is_null:ident
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name {
+            /// Minimum corner.
+            pub min: $point,
+            /// Maximum corner.
+            pub max: $point,
+        }
+
+        impl $name {
+            /// Create a box from its limits.
+            pub fn new(min_x: $real, min_y: $real, max_x: $real, max_y: $real) -> Self {
+                Self {
+                    min: $point { x: min_x, y: min_y },
+                    max: $point { x: max_x, y: max_y },
+                }
+            }
+
+            /// Create a null box, that contains no points. Useful as a starting point
+            /// to accumulate points with `add`.
+            pub fn null() -> Self {
+                Self::from_raw(unsafe { $null })
+            }
+
+            /// Create the box that contains a set of points.
+            pub fn from_points(points: &[$point]) -> Self {
+                Self::from_raw(unsafe { $from_points(points.as_ptr() as _, points.len() as _) })
+            }
+
+            pub(crate) fn from_raw(raw: $raw) -> Self {
+                Self {
+                    min: $point::from_raw(raw.min),
+                    max: $point::from_raw(raw.max),
+                }
+            }
+
+            pub(crate) fn as_raw(&self) -> *const $raw {
+                self as *const Self as *const $raw
+            }
+
+            fn as_raw_mut(&mut self) -> *mut $raw {
+                self as *mut Self as *mut $raw
+            }
+
+            /// Gets the central point of the box.
+            pub fn center(&self) -> $point {
+                $point::from_raw(unsafe { $center(self.as_raw()) })
+            }
+
+            /// Expands the box dimensions to contain a point.
+            pub fn add(&mut self, point: &$point) {
+                unsafe { $add(self.as_raw_mut(), point as *const $point as _) }
+            }
+
+            /// Expands the box dimensions to contain a set of points.
+            pub fn add_points(&mut self, points: &[$point]) {
+                unsafe { $addn(self.as_raw_mut(), points.as_ptr() as _, points.len() as _) }
+            }
+
+            /// Expands the box dimensions to contain a circle.
+            pub fn add_circle(&mut self, circle: &$circle) {
+                unsafe { $add_circle(self.as_raw_mut(), circle.as_raw()) }
+            }
+
+            /// Expands the box dimensions to contain another box.
+            pub fn merge(&mut self, other: &Self) {
+                unsafe { $merge(self.as_raw_mut(), other.as_raw()) }
+            }
+
+            /// Gets the four segments that form the box sides.
+            pub fn segments(&self) -> [$segment; 4] {
+                let mut segments = [$segment::new(0.0, 0.0, 0.0, 0.0); 4];
+                unsafe { $segments(self.as_raw(), segments.as_mut_ptr() as _) };
+                segments
+            }
+
+            /// Gets the area of the box.
+            pub fn area(&self) -> $real {
+                unsafe { $area(self.as_raw()) }
+            }
+
+            /// Checks if the box is null (contains no points).
+            pub fn is_null(&self) -> bool {
+                unsafe { $is_null(self.as_raw()) != 0 }
+            }
+        }
+    };
+}
+
+bounding_box!(
+    /// Axis aligned bounding box.
+    Box2D(f32, Point2D, Circle2D, Segment2D, nappgui_sys::Box2Df),
+    kBOX2D_NULLf,
+    box2d_from_pointsf,
+    box2d_centerf,
+    box2d_addf,
+    box2d_addnf,
+    box2d_add_circlef,
+    box2d_mergef,
+    box2d_segmentsf,
+    box2d_areaf,
+    box2d_is_nullf
+);
+
+bounding_box!(
+    /// Axis aligned bounding box in double precision.
+    Box2Dd(f64, Point2Dd, Circle2Dd, Segment2Dd, nappgui_sys::Box2Dd),
+    kBOX2D_NULLd,
+    box2d_from_pointsd,
+    box2d_centerd,
+    box2d_addd,
+    box2d_addnd,
+    box2d_add_circled,
+    box2d_merged,
+    box2d_segmentsd,
+    box2d_aread,
+    box2d_is_nulld
+);
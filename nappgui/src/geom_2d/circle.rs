@@ -0,0 +1,97 @@
+use nappgui_sys::{
+    cir2d_aread, cir2d_areaf, cir2d_from_boxd, cir2d_from_boxf, cir2d_from_pointsd, cir2d_from_pointsf, cir2d_is_nulld,
+    cir2d_is_nullf, cir2d_minimumd, cir2d_minimumf, kCIR2D_NULLd, kCIR2D_NULLf,
+};
+
+use super::{Box2D, Box2Dd, Point2D, Point2Dd};
+
+macro_rules! circle {
+    (
+        $(#[$attr:meta])*
+        $name:ident($real:ty, $point:ident, $box:ident, $raw:ty),
+        $null:ident, $from_box:ident, $from_points:ident, $minimum:ident, $area:ident, $is_null:ident
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name {
+            /// Center.
+            pub center: $point,
+            /// Radius.
+            pub radius: $real,
+        }
+
+        impl $name {
+            /// Create a circle from its center and radius.
+            pub fn new(x: $real, y: $real, radius: $real) -> Self {
+                Self {
+                    center: $point { x, y },
+                    radius,
+                }
+            }
+
+            /// Create a null circle, with negative radius.
+            pub fn null() -> Self {
+                Self::from_raw(unsafe { $null })
+            }
+
+            /// Create the circle that circumscribes a box.
+            pub fn from_box(bbox: &$box) -> Self {
+                Self::from_raw(unsafe { $from_box(bbox.as_raw()) })
+            }
+
+            /// Create a circle containing a set of points, centered on their centroid.
+            pub fn from_points(points: &[$point]) -> Self {
+                Self::from_raw(unsafe { $from_points(points.as_ptr() as _, points.len() as _) })
+            }
+
+            /// Create the minimum circle containing a set of points.
+            pub fn minimum(points: &[$point]) -> Self {
+                Self::from_raw(unsafe { $minimum(points.as_ptr() as _, points.len() as _) })
+            }
+
+            pub(crate) fn from_raw(raw: $raw) -> Self {
+                Self {
+                    center: $point::from_raw(raw.c),
+                    radius: raw.r,
+                }
+            }
+
+            pub(crate) fn as_raw(&self) -> *const $raw {
+                self as *const Self as *const $raw
+            }
+
+            /// Gets the area of the circle.
+            pub fn area(&self) -> $real {
+                unsafe { $area(self.as_raw()) }
+            }
+
+            /// Checks if the circle is null (radius < 0).
+            pub fn is_null(&self) -> bool {
+                unsafe { $is_null(self.as_raw()) != 0 }
+            }
+        }
+    };
+}
+
+circle!(
+    /// Circle defined by its center and radius.
+    Circle2D(f32, Point2D, Box2D, nappgui_sys::Cir2Df),
+    kCIR2D_NULLf,
+    cir2d_from_boxf,
+    cir2d_from_pointsf,
+    cir2d_minimumf,
+    cir2d_areaf,
+    cir2d_is_nullf
+);
+
+circle!(
+    /// Circle defined by its center and radius in double precision.
+    Circle2Dd(f64, Point2Dd, Box2Dd, nappgui_sys::Cir2Dd),
+    kCIR2D_NULLd,
+    cir2d_from_boxd,
+    cir2d_from_pointsd,
+    cir2d_minimumd,
+    cir2d_aread,
+    cir2d_is_nulld
+);
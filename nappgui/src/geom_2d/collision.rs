@@ -0,0 +1,178 @@
+use nappgui_sys::{
+    col2d_box_boxd, col2d_box_boxf, col2d_box_circled, col2d_box_circlef, col2d_box_pointd, col2d_box_pointf,
+    col2d_box_segmentd, col2d_box_segmentf, col2d_circle_circled, col2d_circle_circlef, col2d_circle_pointd,
+    col2d_circle_pointf, col2d_circle_segmentd, col2d_circle_segmentf, col2d_obb_boxd, col2d_obb_boxf,
+    col2d_obb_circled, col2d_obb_circlef, col2d_obb_obbd, col2d_obb_obbf, col2d_obb_pointd, col2d_obb_pointf,
+    col2d_obb_segmentd, col2d_obb_segmentf, col2d_point_pointd, col2d_point_pointf, col2d_poly_boxd, col2d_poly_boxf,
+    col2d_poly_circled, col2d_poly_circlef, col2d_poly_obbd, col2d_poly_obbf, col2d_poly_pointd, col2d_poly_pointf,
+    col2d_poly_polyd, col2d_poly_polyf, col2d_poly_segmentd, col2d_poly_segmentf, col2d_poly_trid, col2d_poly_trif,
+    col2d_segment_pointd, col2d_segment_pointf, col2d_segment_segmentd, col2d_segment_segmentf, col2d_tri_boxd,
+    col2d_tri_boxf, col2d_tri_circled, col2d_tri_circlef, col2d_tri_obbd, col2d_tri_obbf, col2d_tri_pointd,
+    col2d_tri_pointf, col2d_tri_segmentd, col2d_tri_segmentf, col2d_tri_trid, col2d_tri_trif,
+};
+
+use super::{
+    Box2D, Box2Dd, Circle2D, Circle2Dd, OBB2Dd, Point2D, Point2Dd, Polygon2D, Polygon2Dd, Segment2D, Segment2Dd,
+    Triangle2D, Triangle2Dd, OBB2D,
+};
+
+/// Collision detection between two geometric shapes.
+pub trait Collides<Other: ?Sized> {
+    /// Data returned when both shapes collide.
+    type Collision;
+
+    /// Checks if both shapes collide.
+    fn collides(&self, other: &Other) -> bool;
+
+    /// Checks if both shapes collide, returning the collision data if so.
+    fn collision(&self, other: &Other) -> Option<Self::Collision>;
+}
+
+macro_rules! collision {
+    ($(#[$attr:meta])* $name:ident($real:ty, $point:ident)) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Default, Clone, Copy, PartialEq)]
+        pub struct $name {
+            /// Collision point.
+            pub point: $point,
+            /// Collision normal.
+            pub normal: $point,
+            /// Penetration depth.
+            pub depth: $real,
+        }
+    };
+}
+
+collision!(
+    /// Data about the collision between two shapes.
+    Collision2D(f32, Point2D)
+);
+
+collision!(
+    /// Data about the collision between two shapes in double precision.
+    Collision2Dd(f64, Point2Dd)
+);
+
+macro_rules! collides {
+    ($collision:ident, $($shape:ident, $other:ident => $func:ident;)*) => {
+        // Pointers are cast because a few double precision functions are declared
+        // with single precision arguments in the NAppGUI headers.
+        $(
+            impl Collides<$other> for $shape {
+                type Collision = $collision;
+
+                fn collides(&self, other: &$other) -> bool {
+                    unsafe { $func(self.as_raw() as _, other.as_raw() as _, std::ptr::null_mut()) != 0 }
+                }
+
+                fn collision(&self, other: &$other) -> Option<$collision> {
+                    let mut col = $collision::default();
+                    let collides = unsafe { $func(self.as_raw() as _, other.as_raw() as _, &mut col as *mut $collision as _) != 0 };
+                    collides.then_some(col)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! collides_tol {
+    ($collision:ident, $real:ty, $($shape:ident, $other:ident => $func:ident;)*) => {
+        $(
+            impl $shape {
+                /// Checks if the point collides with this shape, with a tolerance distance.
+                pub fn collision_tol(&self, point: &$other, tol: $real) -> Option<$collision> {
+                    let mut col = $collision::default();
+                    let collides =
+                        unsafe { $func(self.as_raw(), point.as_raw(), tol, &mut col as *mut $collision as _) != 0 };
+                    collides.then_some(col)
+                }
+            }
+
+            /// The check is done without tolerance. Use `collision_tol` to set it.
+            impl Collides<$other> for $shape {
+                type Collision = $collision;
+
+                fn collides(&self, other: &$other) -> bool {
+                    unsafe { $func(self.as_raw(), other.as_raw(), 0.0, std::ptr::null_mut()) != 0 }
+                }
+
+                fn collision(&self, other: &$other) -> Option<$collision> {
+                    self.collision_tol(other, 0.0)
+                }
+            }
+        )*
+    };
+}
+
+collides_tol!(
+    Collision2D, f32,
+    Point2D, Point2D => col2d_point_pointf;
+    Segment2D, Point2D => col2d_segment_pointf;
+);
+
+collides_tol!(
+    Collision2Dd, f64,
+    Point2Dd, Point2Dd => col2d_point_pointd;
+    Segment2Dd, Point2Dd => col2d_segment_pointd;
+);
+
+collides!(
+    Collision2D,
+    Segment2D, Segment2D => col2d_segment_segmentf;
+    Circle2D, Point2D => col2d_circle_pointf;
+    Circle2D, Segment2D => col2d_circle_segmentf;
+    Circle2D, Circle2D => col2d_circle_circlef;
+    Box2D, Point2D => col2d_box_pointf;
+    Box2D, Segment2D => col2d_box_segmentf;
+    Box2D, Circle2D => col2d_box_circlef;
+    Box2D, Box2D => col2d_box_boxf;
+    OBB2D, Point2D => col2d_obb_pointf;
+    OBB2D, Segment2D => col2d_obb_segmentf;
+    OBB2D, Circle2D => col2d_obb_circlef;
+    OBB2D, Box2D => col2d_obb_boxf;
+    OBB2D, OBB2D => col2d_obb_obbf;
+    Triangle2D, Point2D => col2d_tri_pointf;
+    Triangle2D, Segment2D => col2d_tri_segmentf;
+    Triangle2D, Circle2D => col2d_tri_circlef;
+    Triangle2D, Box2D => col2d_tri_boxf;
+    Triangle2D, OBB2D => col2d_tri_obbf;
+    Triangle2D, Triangle2D => col2d_tri_trif;
+    Polygon2D, Point2D => col2d_poly_pointf;
+    Polygon2D, Segment2D => col2d_poly_segmentf;
+    Polygon2D, Circle2D => col2d_poly_circlef;
+    Polygon2D, Box2D => col2d_poly_boxf;
+    Polygon2D, OBB2D => col2d_poly_obbf;
+    Polygon2D, Triangle2D => col2d_poly_trif;
+    Polygon2D, Polygon2D => col2d_poly_polyf;
+);
+
+collides!(
+    Collision2Dd,
+    Segment2Dd, Segment2Dd => col2d_segment_segmentd;
+    Circle2Dd, Point2Dd => col2d_circle_pointd;
+    Circle2Dd, Segment2Dd => col2d_circle_segmentd;
+    Circle2Dd, Circle2Dd => col2d_circle_circled;
+    Box2Dd, Point2Dd => col2d_box_pointd;
+    Box2Dd, Segment2Dd => col2d_box_segmentd;
+    Box2Dd, Circle2Dd => col2d_box_circled;
+    Box2Dd, Box2Dd => col2d_box_boxd;
+    OBB2Dd, Point2Dd => col2d_obb_pointd;
+    OBB2Dd, Segment2Dd => col2d_obb_segmentd;
+    OBB2Dd, Circle2Dd => col2d_obb_circled;
+    OBB2Dd, Box2Dd => col2d_obb_boxd;
+    OBB2Dd, OBB2Dd => col2d_obb_obbd;
+    Triangle2Dd, Point2Dd => col2d_tri_pointd;
+    Triangle2Dd, Segment2Dd => col2d_tri_segmentd;
+    Triangle2Dd, Circle2Dd => col2d_tri_circled;
+    Triangle2Dd, Box2Dd => col2d_tri_boxd;
+    Triangle2Dd, OBB2Dd => col2d_tri_obbd;
+    Triangle2Dd, Triangle2Dd => col2d_tri_trid;
+    Polygon2Dd, Point2Dd => col2d_poly_pointd;
+    Polygon2Dd, Segment2Dd => col2d_poly_segmentd;
+    Polygon2Dd, Circle2Dd => col2d_poly_circled;
+    Polygon2Dd, Box2Dd => col2d_poly_boxd;
+    Polygon2Dd, OBB2Dd => col2d_poly_obbd;
+    Polygon2Dd, Triangle2Dd => col2d_poly_trid;
+    Polygon2Dd, Polygon2Dd => col2d_poly_polyd;
+);
@@ -0,0 +1,79 @@
+pub(crate) mod box2d;
+pub(crate) mod circle;
+pub(crate) mod collision;
+pub(crate) mod obb;
+pub(crate) mod polygon;
+pub(crate) mod segment;
+pub(crate) mod transform;
+pub(crate) mod triangle;
+
+pub use {
+    box2d::{Box2D, Box2Dd},
+    circle::{Circle2D, Circle2Dd},
+    collision::{Collides, Collision2D, Collision2Dd},
+    obb::{OBB2Dd, OBB2D},
+    polygon::{Polygon2D, Polygon2Dd},
+    segment::{Segment2D, Segment2Dd},
+    transform::{Transformation2D, Transformation2Dd},
+    triangle::{Triangle2D, Triangle2Dd},
+};
+
+/// Represents a 2d vector or point.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Point2D {
+    /// Coordinate x.
+    pub x: f32,
+    /// Coordinate y.
+    pub y: f32,
+}
+
+/// Represents a 2d vector or point in double precision.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Point2Dd {
+    /// Coordinate x.
+    pub x: f64,
+    /// Coordinate y.
+    pub y: f64,
+}
+
+impl Point2D {
+    pub(crate) fn from_raw(raw: nappgui_sys::V2Df) -> Self {
+        Self { x: raw.x, y: raw.y }
+    }
+
+    pub(crate) fn as_raw(&self) -> *const nappgui_sys::V2Df {
+        self as *const Self as *const nappgui_sys::V2Df
+    }
+}
+
+impl Point2Dd {
+    pub(crate) fn from_raw(raw: nappgui_sys::V2Dd) -> Self {
+        Self { x: raw.x, y: raw.y }
+    }
+
+    pub(crate) fn as_raw(&self) -> *const nappgui_sys::V2Dd {
+        self as *const Self as *const nappgui_sys::V2Dd
+    }
+}
+
+/// Represents a 2d size.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size2D {
+    /// Width.
+    pub width: f32,
+    /// Height.
+    pub height: f32,
+}
+
+/// 2d rectangle.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect2D {
+    /// Position.
+    pub position: Point2D,
+    /// Size.
+    pub size: Size2D,
+}
@@ -0,0 +1,160 @@
+use nappgui_sys::{
+    obb2d_angled, obb2d_anglef, obb2d_aread, obb2d_areaf, obb2d_boxd, obb2d_boxf, obb2d_centerd, obb2d_centerf,
+    obb2d_copyd, obb2d_copyf, obb2d_cornersd, obb2d_cornersf, obb2d_created, obb2d_createf, obb2d_destroyd,
+    obb2d_destroyf, obb2d_from_lined, obb2d_from_linef, obb2d_from_pointsd, obb2d_from_pointsf, obb2d_heightd,
+    obb2d_heightf, obb2d_moved, obb2d_movef, obb2d_transformd, obb2d_transformf, obb2d_updated, obb2d_updatef,
+    obb2d_widthd, obb2d_widthf,
+};
+
+use super::{Box2D, Box2Dd, Point2D, Point2Dd, Transformation2D, Transformation2Dd};
+
+macro_rules! oriented_box {
+    (
+        $(#[$attr:meta])*
+        $name:ident($real:ty, $point:ident, $box:ident, $t2d:ident, $raw:ty),
+        $create:ident, $from_line:ident, $from_points:ident, $copy:ident, $destroy:ident, $update:ident,
+        $move:ident, $transform:ident, $corners:ident, $center:ident, $width:ident, $height:ident,
+        $angle:ident, $area:ident, $bbox:ident
+    ) => {
+        $(#[$attr])*
+        pub struct $name {
+            pub(crate) inner: *mut $raw,
+        }
+
+        impl $name {
+            pub(crate) fn new(ptr: *mut $raw) -> Self {
+                if ptr.is_null() {
+                    panic!(concat!(stringify!($name), " is null"));
+                }
+                Self { inner: ptr }
+            }
+
+            pub(crate) fn as_raw(&self) -> *const $raw {
+                self.inner
+            }
+
+            /// Create an oriented box from its center, size and angle (in radians).
+            pub fn create(center: &$point, width: $real, height: $real, angle: $real) -> Self {
+                Self::new(unsafe { $create(center as *const $point as _, width, height, angle) })
+            }
+
+            /// Create an oriented box from a segment, with the given thickness.
+            pub fn from_line(p0: &$point, p1: &$point, thickness: $real) -> Self {
+                Self::new(unsafe { $from_line(p0 as *const $point as _, p1 as *const $point as _, thickness) })
+            }
+
+            /// Create the oriented box that best fits a set of points.
+            pub fn from_points(points: &[$point]) -> Self {
+                Self::new(unsafe { $from_points(points.as_ptr() as _, points.len() as _) })
+            }
+
+            /// Updates the parameters of the box.
+            pub fn update(&mut self, center: &$point, width: $real, height: $real, angle: $real) {
+                unsafe { $update(self.inner, center as *const $point as _, width, height, angle) }
+            }
+
+            /// Moves the box.
+            pub fn move_by(&mut self, offset_x: $real, offset_y: $real) {
+                unsafe { $move(self.inner, offset_x, offset_y) }
+            }
+
+            /// Applies a 2D transformation to the box.
+            pub fn transform(&mut self, t2d: &$t2d) {
+                unsafe { $transform(self.inner, t2d as *const $t2d as _) }
+            }
+
+            /// Gets the four vertices of the box.
+            pub fn corners(&self) -> [$point; 4] {
+                let corners = unsafe { std::slice::from_raw_parts($corners(self.inner), 4) };
+                [
+                    $point::from_raw(corners[0]),
+                    $point::from_raw(corners[1]),
+                    $point::from_raw(corners[2]),
+                    $point::from_raw(corners[3]),
+                ]
+            }
+
+            /// Gets the center of the box.
+            pub fn center(&self) -> $point {
+                $point::from_raw(unsafe { $center(self.inner) })
+            }
+
+            /// Gets the width of the box.
+            pub fn width(&self) -> $real {
+                unsafe { $width(self.inner) }
+            }
+
+            /// Gets the height of the box.
+            pub fn height(&self) -> $real {
+                unsafe { $height(self.inner) }
+            }
+
+            /// Gets the angle of the box, in radians.
+            pub fn angle(&self) -> $real {
+                unsafe { $angle(self.inner) }
+            }
+
+            /// Gets the area of the box.
+            pub fn area(&self) -> $real {
+                unsafe { $area(self.inner) }
+            }
+
+            /// Gets the axis aligned bounding box that contains this box.
+            pub fn bounding_box(&self) -> $box {
+                $box::from_raw(unsafe { $bbox(self.inner) })
+            }
+        }
+
+        impl Clone for $name {
+            fn clone(&self) -> Self {
+                Self::new(unsafe { $copy(self.inner) })
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                unsafe { $destroy(&mut self.inner) }
+            }
+        }
+    };
+}
+
+oriented_box!(
+    /// Oriented box (rotated rectangle).
+    OBB2D(f32, Point2D, Box2D, Transformation2D, nappgui_sys::OBB2Df),
+    obb2d_createf,
+    obb2d_from_linef,
+    obb2d_from_pointsf,
+    obb2d_copyf,
+    obb2d_destroyf,
+    obb2d_updatef,
+    obb2d_movef,
+    obb2d_transformf,
+    obb2d_cornersf,
+    obb2d_centerf,
+    obb2d_widthf,
+    obb2d_heightf,
+    obb2d_anglef,
+    obb2d_areaf,
+    obb2d_boxf
+);
+
+oriented_box!(
+    /// Oriented box (rotated rectangle) in double precision.
+    OBB2Dd(f64, Point2Dd, Box2Dd, Transformation2Dd, nappgui_sys::OBB2Dd),
+    obb2d_created,
+    obb2d_from_lined,
+    obb2d_from_pointsd,
+    obb2d_copyd,
+    obb2d_destroyd,
+    obb2d_updated,
+    obb2d_moved,
+    obb2d_transformd,
+    obb2d_cornersd,
+    obb2d_centerd,
+    obb2d_widthd,
+    obb2d_heightd,
+    obb2d_angled,
+    obb2d_aread,
+    obb2d_boxd
+);
@@ -0,0 +1,181 @@
+use nappgui_sys::{
+    array_all, array_destroy, array_destroy_ptr, array_size, pol2d_aread, pol2d_areaf, pol2d_boxd, pol2d_boxf,
+    pol2d_ccwd, pol2d_ccwf, pol2d_centroidd, pol2d_centroidf, pol2d_convex_hulld, pol2d_convex_hullf,
+    pol2d_convex_partitiond, pol2d_convex_partitionf, pol2d_convexd, pol2d_convexf, pol2d_copyd, pol2d_copyf,
+    pol2d_created, pol2d_createf, pol2d_destroyd, pol2d_destroyf, pol2d_nd, pol2d_nf, pol2d_pointsd, pol2d_pointsf,
+    pol2d_transformd, pol2d_transformf, pol2d_trianglesd, pol2d_trianglesf, pol2d_visual_centerd, pol2d_visual_centerf,
+    Array,
+};
+
+use super::{Box2D, Box2Dd, Point2D, Point2Dd, Transformation2D, Transformation2Dd, Triangle2D, Triangle2Dd};
+
+macro_rules! polygon {
+    (
+        $(#[$attr:meta])*
+        $name:ident($real:ty, $point:ident, $box:ident, $triangle:ident, $t2d:ident, $raw:ty),
+        $create:ident, $convex_hull:ident, $copy:ident, $destroy:ident, $transform:ident, $points:ident,
+        $n:ident, $area:ident, $bbox:ident, $ccw:ident, $convex:ident, $centroid:ident, $visual_center:ident,
+        $triangles:ident, $convex_partition:ident, $tri_type:literal, $pol_type:literal
+    ) => {
+        $(#[$attr])*
+        pub struct $name {
+            pub(crate) inner: *mut $raw,
+        }
+
+        impl $name {
+            pub(crate) fn new(ptr: *mut $raw) -> Self {
+                if ptr.is_null() {
+                    panic!(concat!(stringify!($name), " is null"));
+                }
+                Self { inner: ptr }
+            }
+
+            pub(crate) fn as_raw(&self) -> *const $raw {
+                self.inner
+            }
+
+            /// Create a polygon from its vertices.
+            pub fn create(points: &[$point]) -> Self {
+                Self::new(unsafe { $create(points.as_ptr() as _, points.len() as _) })
+            }
+
+            /// Create the convex polygon that surrounds a set of points (convex hull).
+            pub fn convex_hull(points: &[$point]) -> Self {
+                Self::new(unsafe { $convex_hull(points.as_ptr() as _, points.len() as _) })
+            }
+
+            /// Applies a 2D transformation to the polygon.
+            pub fn transform(&mut self, t2d: &$t2d) {
+                unsafe { $transform(self.inner, t2d as *const $t2d as _) }
+            }
+
+            /// Gets the vertices of the polygon.
+            pub fn points(&self) -> &[$point] {
+                let n = unsafe { $n(self.inner) };
+                let ptr = unsafe { $points(self.inner) };
+                unsafe { std::slice::from_raw_parts(ptr as *const $point, n as usize) }
+            }
+
+            /// Gets the area of the polygon.
+            pub fn area(&self) -> $real {
+                unsafe { $area(self.inner) }
+            }
+
+            /// Gets the axis aligned bounding box that contains the polygon.
+            pub fn bounding_box(&self) -> $box {
+                $box::from_raw(unsafe { $bbox(self.inner) })
+            }
+
+            /// Checks if the vertices of the polygon are in counter-clockwise order.
+            pub fn ccw(&self) -> bool {
+                unsafe { $ccw(self.inner) != 0 }
+            }
+
+            /// Checks if the polygon is convex.
+            pub fn convex(&self) -> bool {
+                unsafe { $convex(self.inner) != 0 }
+            }
+
+            /// Gets the centroid (center of mass) of the polygon.
+            pub fn centroid(&self) -> $point {
+                $point::from_raw(unsafe { $centroid(self.inner) })
+            }
+
+            /// Gets the visual center, or pole of inaccessibility, of the polygon. This is the
+            /// interior point furthest from any edge, useful to place labels.
+            ///
+            /// `norm_tol` is the tolerance, normalized to the polygon dimensions (e.g. 0.05).
+            pub fn visual_center(&self, norm_tol: $real) -> $point {
+                $point::from_raw(unsafe { $visual_center(self.inner, norm_tol) })
+            }
+
+            /// Gets the list of triangles that make up the polygon (triangulation).
+            pub fn triangles(&self) -> Vec<$triangle> {
+                let mut array = unsafe { $triangles(self.inner) } as *mut Array;
+                let size = unsafe { array_size(array) } as usize;
+                let ptr = unsafe { array_all(array) } as *const $triangle;
+                let triangles = if size == 0 {
+                    Vec::new()
+                } else {
+                    unsafe { std::slice::from_raw_parts(ptr, size) }.to_vec()
+                };
+                unsafe { array_destroy(&mut array, None, $tri_type.as_ptr()) };
+                triangles
+            }
+
+            /// Gets the list of convex polygons that make up this polygon (convex partition).
+            pub fn convex_partition(&self) -> Vec<$name> {
+                let mut array = unsafe { $convex_partition(self.inner) } as *mut Array;
+                let size = unsafe { array_size(array) } as usize;
+                let ptr = unsafe { array_all(array) } as *const *mut $raw;
+                let polygons = if size == 0 {
+                    Vec::new()
+                } else {
+                    // The vector takes the ownership of each polygon.
+                    unsafe { std::slice::from_raw_parts(ptr, size) }
+                        .iter()
+                        .map(|pol| Self::new(*pol))
+                        .collect()
+                };
+                unsafe { array_destroy_ptr(&mut array, None, $pol_type.as_ptr()) };
+                polygons
+            }
+        }
+
+        impl Clone for $name {
+            fn clone(&self) -> Self {
+                Self::new(unsafe { $copy(self.inner) })
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                unsafe { $destroy(&mut self.inner) }
+            }
+        }
+    };
+}
+
+polygon!(
+    /// Polygon defined by a list of vertices.
+    Polygon2D(f32, Point2D, Box2D, Triangle2D, Transformation2D, nappgui_sys::Pol2Df),
+    pol2d_createf,
+    pol2d_convex_hullf,
+    pol2d_copyf,
+    pol2d_destroyf,
+    pol2d_transformf,
+    pol2d_pointsf,
+    pol2d_nf,
+    pol2d_areaf,
+    pol2d_boxf,
+    pol2d_ccwf,
+    pol2d_convexf,
+    pol2d_centroidf,
+    pol2d_visual_centerf,
+    pol2d_trianglesf,
+    pol2d_convex_partitionf,
+    c"Tri2Df",
+    c"Pol2Df"
+);
+
+polygon!(
+    /// Polygon defined by a list of vertices in double precision.
+    Polygon2Dd(f64, Point2Dd, Box2Dd, Triangle2Dd, Transformation2Dd, nappgui_sys::Pol2Dd),
+    pol2d_created,
+    pol2d_convex_hulld,
+    pol2d_copyd,
+    pol2d_destroyd,
+    pol2d_transformd,
+    pol2d_pointsd,
+    pol2d_nd,
+    pol2d_aread,
+    pol2d_boxd,
+    pol2d_ccwd,
+    pol2d_convexd,
+    pol2d_centroidd,
+    pol2d_visual_centerd,
+    pol2d_trianglesd,
+    pol2d_convex_partitiond,
+    c"Tri2Dd",
+    c"Pol2Dd"
+);
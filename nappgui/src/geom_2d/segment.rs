@@ -0,0 +1,102 @@
+use nappgui_sys::{
+    seg2d_close_paramd, seg2d_close_paramf, seg2d_evald, seg2d_evalf, seg2d_lengthd, seg2d_lengthf,
+    seg2d_point_sqdistd, seg2d_point_sqdistf, seg2d_sqdistd, seg2d_sqdistf, seg2d_sqlengthd, seg2d_sqlengthf,
+};
+
+use super::{Point2D, Point2Dd};
+
+macro_rules! segment {
+    (
+        $(#[$attr:meta])*
+        $name:ident($real:ty, $point:ident, $raw:ty),
+        $length:ident, $sqlength:ident, $eval:ident, $close_param:ident, $point_sqdist:ident, $sqdist:ident
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name {
+            /// Start point.
+            pub p0: $point,
+            /// End point.
+            pub p1: $point,
+        }
+
+        impl $name {
+            /// Create a segment from the coordinates of its endpoints.
+            pub fn new(x0: $real, y0: $real, x1: $real, y1: $real) -> Self {
+                Self {
+                    p0: $point { x: x0, y: y0 },
+                    p1: $point { x: x1, y: y1 },
+                }
+            }
+
+            /// Create a segment from its endpoints.
+            pub fn from_points(p0: $point, p1: $point) -> Self {
+                Self { p0, p1 }
+            }
+
+            pub(crate) fn as_raw(&self) -> *const $raw {
+                self as *const Self as *const $raw
+            }
+
+            /// Gets the length of the segment.
+            pub fn length(&self) -> $real {
+                unsafe { $length(self.as_raw()) }
+            }
+
+            /// Gets the square of the length of the segment. It avoids calculating the square root.
+            pub fn sqlength(&self) -> $real {
+                unsafe { $sqlength(self.as_raw()) }
+            }
+
+            /// Gets a point of the segment from its parameter. 0 returns p0, 1 returns p1.
+            pub fn eval(&self, t: $real) -> $point {
+                $point::from_raw(unsafe { $eval(self.as_raw(), t) })
+            }
+
+            /// Gets the parameter of the segment point closest to another given point.
+            pub fn close_param(&self, point: &$point) -> $real {
+                unsafe { $close_param(self.as_raw(), point as *const $point as _) }
+            }
+
+            /// Gets the square of the distance from a point to the segment, along with the
+            /// parameter of the closest point of the segment.
+            pub fn point_sqdist(&self, point: &$point) -> ($real, $real) {
+                let mut t = 0.0;
+                let dist = unsafe { $point_sqdist(self.as_raw(), point as *const $point as _, &mut t) };
+                (dist, t)
+            }
+
+            /// Gets the square of the distance between two segments, along with the parameters
+            /// of the closest points of each one.
+            pub fn sqdist(&self, other: &Self) -> ($real, $real, $real) {
+                let mut t1 = 0.0;
+                let mut t2 = 0.0;
+                let dist = unsafe { $sqdist(self.as_raw(), other.as_raw(), &mut t1, &mut t2) };
+                (dist, t1, t2)
+            }
+        }
+    };
+}
+
+segment!(
+    /// Segment between two points.
+    Segment2D(f32, Point2D, nappgui_sys::Seg2Df),
+    seg2d_lengthf,
+    seg2d_sqlengthf,
+    seg2d_evalf,
+    seg2d_close_paramf,
+    seg2d_point_sqdistf,
+    seg2d_sqdistf
+);
+
+segment!(
+    /// Segment between two points in double precision.
+    Segment2Dd(f64, Point2Dd, nappgui_sys::Seg2Dd),
+    seg2d_lengthd,
+    seg2d_sqlengthd,
+    seg2d_evald,
+    seg2d_close_paramd,
+    seg2d_point_sqdistd,
+    seg2d_sqdistd
+);
@@ -0,0 +1,183 @@
+use std::ops::{Mul, MulAssign};
+
+use nappgui_sys::{
+    t2d_decomposed, t2d_decomposef, t2d_inversed, t2d_inversef, t2d_invfastd, t2d_invfastf, t2d_moved, t2d_movef,
+    t2d_multd, t2d_multf, t2d_rotated, t2d_rotatef, t2d_scaled, t2d_scalef, t2d_vmultd, t2d_vmultf, t2d_vmultnd,
+    t2d_vmultnf,
+};
+
+use super::{Point2D, Point2Dd};
+
+macro_rules! transformation {
+    (
+        $(#[$attr:meta])*
+        $name:ident($real:ty, $point:ident, $raw:ty),
+        $move:ident, $rotate:ident, $scale:ident, $invfast:ident, $inverse:ident, $mult:ident,
+        $vmult:ident, $vmultn:ident, $decompose:ident
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name {
+            /// Component i of the linear transformation.
+            pub i: $point,
+            /// Component j of the linear transformation.
+            pub j: $point,
+            /// Position.
+            pub position: $point,
+        }
+
+        impl $name {
+            /// Create the identity transformation.
+            pub fn identity() -> Self {
+                Self {
+                    i: $point { x: 1.0, y: 0.0 },
+                    j: $point { x: 0.0, y: 1.0 },
+                    position: $point { x: 0.0, y: 0.0 },
+                }
+            }
+
+            /// Create a translation.
+            pub fn from_translation(x: $real, y: $real) -> Self {
+                Self::identity().translate(x, y)
+            }
+
+            /// Create a rotation, in radians.
+            pub fn from_rotation(angle: $real) -> Self {
+                Self::identity().rotate(angle)
+            }
+
+            /// Create a scaling.
+            pub fn from_scale(sx: $real, sy: $real) -> Self {
+                Self::identity().scale(sx, sy)
+            }
+
+            pub(crate) fn as_raw(&self) -> *const $raw {
+                self as *const Self as *const $raw
+            }
+
+            fn apply_raw(&self, func: impl FnOnce(*mut $raw, *const $raw)) -> Self {
+                let mut dest = Self::identity();
+                func(&mut dest as *mut Self as *mut $raw, self.as_raw());
+                dest
+            }
+
+            /// Multiplies the transformation by a translation.
+            pub fn translate(&self, x: $real, y: $real) -> Self {
+                self.apply_raw(|dest, src| unsafe { $move(dest, src, x, y) })
+            }
+
+            /// Multiplies the transformation by a rotation, in radians.
+            pub fn rotate(&self, angle: $real) -> Self {
+                self.apply_raw(|dest, src| unsafe { $rotate(dest, src, angle) })
+            }
+
+            /// Multiplies the transformation by a scaling.
+            pub fn scale(&self, sx: $real, sy: $real) -> Self {
+                self.apply_raw(|dest, src| unsafe { $scale(dest, src, sx, sy) })
+            }
+
+            /// Calculates the inverse transformation.
+            pub fn inverse(&self) -> Self {
+                self.apply_raw(|dest, src| unsafe { $inverse(dest, src) })
+            }
+
+            /// Calculates the inverse of an orthogonal transformation (only translations
+            /// and rotations). Faster than `inverse`, but the result is wrong if there is scaling.
+            pub fn inverse_fast(&self) -> Self {
+                self.apply_raw(|dest, src| unsafe { $invfast(dest, src) })
+            }
+
+            /// Multiplies two transformations (`self * other`).
+            pub fn mult(&self, other: &Self) -> Self {
+                self.apply_raw(|dest, src| unsafe { $mult(dest, src, other.as_raw()) })
+            }
+
+            /// Transforms a point.
+            pub fn apply(&self, point: &$point) -> $point {
+                let mut dest = $point::default();
+                unsafe { $vmult(&mut dest as *mut $point as _, self.as_raw(), point.as_raw()) };
+                dest
+            }
+
+            /// Transforms a set of points.
+            pub fn apply_points(&self, points: &[$point]) -> Vec<$point> {
+                let mut dest = vec![$point::default(); points.len()];
+                unsafe { $vmultn(dest.as_mut_ptr() as _, self.as_raw(), points.as_ptr() as _, points.len() as _) };
+                dest
+            }
+
+            /// Gets the components of the transformation. Returns (position, angle, scale).
+            pub fn decompose(&self) -> ($point, $real, $point) {
+                let mut position = $point::default();
+                let mut angle = 0.0;
+                let mut scale = $point::default();
+                unsafe {
+                    $decompose(
+                        self.as_raw(),
+                        &mut position as *mut $point as _,
+                        &mut angle,
+                        &mut scale as *mut $point as _,
+                    )
+                };
+                (position, angle, scale)
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                self.mult(&rhs)
+            }
+        }
+
+        impl MulAssign for $name {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = self.mult(&rhs);
+            }
+        }
+
+        impl Mul<$point> for $name {
+            type Output = $point;
+
+            fn mul(self, rhs: $point) -> $point {
+                self.apply(&rhs)
+            }
+        }
+    };
+}
+
+transformation!(
+    /// 2d affine transformation.
+    Transformation2D(f32, Point2D, nappgui_sys::T2Df),
+    t2d_movef,
+    t2d_rotatef,
+    t2d_scalef,
+    t2d_invfastf,
+    t2d_inversef,
+    t2d_multf,
+    t2d_vmultf,
+    t2d_vmultnf,
+    t2d_decomposef
+);
+
+transformation!(
+    /// 2d affine transformation in double precision.
+    Transformation2Dd(f64, Point2Dd, nappgui_sys::T2Dd),
+    t2d_moved,
+    t2d_rotated,
+    t2d_scaled,
+    t2d_invfastd,
+    t2d_inversed,
+    t2d_multd,
+    t2d_vmultd,
+    t2d_vmultnd,
+    t2d_decomposed
+);
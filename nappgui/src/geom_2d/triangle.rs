@@ -0,0 +1,84 @@
+use nappgui_sys::{
+    tri2d_aread, tri2d_areaf, tri2d_ccwd, tri2d_ccwf, tri2d_centroidd, tri2d_centroidf, tri2d_transformd,
+    tri2d_transformf,
+};
+
+use super::{Point2D, Point2Dd, Transformation2D, Transformation2Dd};
+
+macro_rules! triangle {
+    (
+        $(#[$attr:meta])*
+        $name:ident($real:ty, $point:ident, $t2d:ident, $raw:ty),
+        $transform:ident, $area:ident, $ccw:ident, $centroid:ident
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name {
+            /// First vertex.
+            pub p0: $point,
+            /// Second vertex.
+            pub p1: $point,
+            /// Third vertex.
+            pub p2: $point,
+        }
+
+        impl $name {
+            /// Create a triangle from the coordinates of its vertices.
+            pub fn new(x0: $real, y0: $real, x1: $real, y1: $real, x2: $real, y2: $real) -> Self {
+                Self {
+                    p0: $point { x: x0, y: y0 },
+                    p1: $point { x: x1, y: y1 },
+                    p2: $point { x: x2, y: y2 },
+                }
+            }
+
+            /// Create a triangle from its vertices.
+            pub fn from_points(p0: $point, p1: $point, p2: $point) -> Self {
+                Self { p0, p1, p2 }
+            }
+
+            pub(crate) fn as_raw(&self) -> *const $raw {
+                self as *const Self as *const $raw
+            }
+
+            /// Applies a 2D transformation to the triangle.
+            pub fn transform(&mut self, t2d: &$t2d) {
+                unsafe { $transform(self as *mut Self as *mut $raw, t2d as *const $t2d as _) }
+            }
+
+            /// Gets the area of the triangle.
+            pub fn area(&self) -> $real {
+                unsafe { $area(self.as_raw()) }
+            }
+
+            /// Checks if the vertices of the triangle are in counter-clockwise order.
+            pub fn ccw(&self) -> bool {
+                unsafe { $ccw(self.as_raw()) != 0 }
+            }
+
+            /// Gets the centroid (center of mass) of the triangle.
+            pub fn centroid(&self) -> $point {
+                $point::from_raw(unsafe { $centroid(self.as_raw()) })
+            }
+        }
+    };
+}
+
+triangle!(
+    /// Triangle defined by its three vertices.
+    Triangle2D(f32, Point2D, Transformation2D, nappgui_sys::Tri2Df),
+    tri2d_transformf,
+    tri2d_areaf,
+    tri2d_ccwf,
+    tri2d_centroidf
+);
+
+triangle!(
+    /// Triangle defined by its three vertices in double precision.
+    Triangle2Dd(f64, Point2Dd, Transformation2Dd, nappgui_sys::Tri2Dd),
+    tri2d_transformd,
+    tri2d_aread,
+    tri2d_ccwd,
+    tri2d_centroidd
+);
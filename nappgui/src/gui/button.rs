@@ -5,7 +5,13 @@ use std::{
 
 use crate::{
     draw_2d::{Font, Image},
-    gui::{define_object, event::ButtonEvent, listener, Callback},
+    gui::{
+        define_object,
+        event::ButtonEvent,
+        listener,
+        locale::{forget_localized_text, set_localized_text, Localized},
+        Callback,
+    },
     types::{GuiPosition, GuiState},
 };
 
@@ -78,10 +84,19 @@ impl Button {
     /// # Remarks
     /// In flat buttons, the text will be displayed as tooltip.
     pub fn set_text(&self, text: &str) {
+        forget_localized_text(Localized::Button(*self));
         let text = CString::new(text).unwrap();
         unsafe { button_text(self.as_ptr(), text.as_ptr()) };
     }
 
+    /// Set the text of the button from a resource.
+    ///
+    /// # Remarks
+    /// The text is set again when the language changes with gui_language.
+    pub fn set_text_rid(&self, rid: &str) {
+        set_localized_text(Localized::Button(*self), rid);
+    }
+
     /// Set an alternative text.
     ///
     /// # Remarks
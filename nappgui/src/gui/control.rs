@@ -52,6 +52,7 @@ impl_control!(Progress, as_progress, guicontrol_progress);
 impl_control!(Slider, as_slider, guicontrol_slider);
 impl_control!(SplitView, as_splitview, guicontrol_splitview);
 impl_control!(TableView, as_tableview, guicontrol_tableview);
+impl_control!(Tabs, as_tabs, guicontrol_tabs);
 impl_control!(TextView, as_textview, guicontrol_textview);
 impl_control!(UpDown, as_updown, guicontrol_updown);
 impl_control!(View, as_view, guicontrol_view);
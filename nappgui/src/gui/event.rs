@@ -1,17 +1,18 @@
 use std::ffi::{CStr, CString};
 
 use nappgui_sys::{
-    EvButton, EvDraw, EvKey, EvMenu, EvMouse, EvPos, EvScroll, EvSize, EvSlider, EvTbCell, EvTbPos, EvTbRect, EvTbRow,
-    EvTbSel, EvText, EvTextFilter, EvWheel, EvWinClose,
+    EvButton, EvDraw, EvKey, EvMenu, EvMouse, EvPos, EvScroll, EvSize, EvSlider, EvTbCell, EvTbExpand, EvTbNode,
+    EvTbNodeInfo, EvTbPos, EvTbRect, EvTbRow, EvTbSel, EvText, EvTextFilter, EvWheel, EvWinClose,
 };
 
 use crate::{
     core::{event::NappGUIEventResult, NappGUIEventParams},
     draw_2d::{DCtx, Image},
+    gui::TreeNode,
     types::{Align, GuiMouse, GuiOrient, GuiScroll, GuiState, KeyCode},
 };
 
-/// Parameters of the OnClick event of a button or OnSelect of a popup.
+/// Parameters of the OnClick event of a button or OnSelect of a popup or tabs.
 pub struct ButtonEvent {
     /// Button or item index.
     pub index: u32,
@@ -300,6 +301,8 @@ pub struct TablePositionEvent {
     pub x: f32,
     /// Y coordinate.
     pub y: f32,
+    /// Node of the cell, if the table is in tree mode.
+    pub node: Option<TreeNode>,
 }
 
 impl NappGUIEventParams for TablePositionEvent {
@@ -310,6 +313,7 @@ impl NappGUIEventParams for TablePositionEvent {
         TablePositionEvent {
             x: event.col as f32,
             y: event.row as f32,
+            node: TreeNode::from_ptr(event.node),
         }
     }
 }
@@ -397,7 +401,7 @@ pub struct TableCellEvent {
     /// Text alignment.
     pub align: Align,
     /// Icon.
-    pub icon: Image,
+    pub icon: Option<Image>,
 }
 
 impl NappGUIEventParams for TableCellEvent {
@@ -408,26 +412,107 @@ impl NappGUIEventParams for TableCellEvent {
         TableCellEvent {
             text: unsafe { CStr::from_ptr(event.text).to_string_lossy().into_owned() },
             align: Align::try_from(event.align).unwrap(),
-            icon: unsafe { Image::from_raw_cloned(event.icon) },
+            icon: (!event.icon.is_null()).then(|| unsafe { Image::from_raw_cloned(event.icon) }),
         }
     }
 }
 
+thread_local! {
+    // The table reads the cell after the OnData event returns, so the text and the icon
+    // must live until the next cell is requested.
+    static TABLE_CELL: std::cell::RefCell<(CString, Option<Image>)> = Default::default();
+}
+
 impl NappGUIEventResult for TableCellEvent {
     type CType = EvTbCell;
     const TYPE: &'static CStr = c"EvTbCell";
 
     fn to(&self) -> Self::CType {
         let text = CString::new(self.text.as_str()).unwrap();
-        let icon = self.icon.clone();
-        nappgui_sys::EvTbCell {
-            text: text.as_ptr(),
-            align: self.align as _,
-            icon: icon.as_ptr(),
+        TABLE_CELL.with_borrow_mut(|cell| {
+            *cell = (text, self.icon.clone());
+            nappgui_sys::EvTbCell {
+                text: cell.0.as_ptr(),
+                align: self.align as _,
+                icon: cell
+                    .1
+                    .as_ref()
+                    .map(|icon| icon.as_ptr())
+                    .unwrap_or(std::ptr::null_mut()),
+            }
+        })
+    }
+}
+
+/// Child of a node in a table in tree mode.
+pub struct TableNodeEvent {
+    /// Parent node. None for the first level of the tree.
+    pub parent: Option<TreeNode>,
+    /// Child index.
+    pub index: u32,
+}
+
+impl NappGUIEventParams for TableNodeEvent {
+    type CType = EvTbNode;
+    const TYPE: &'static CStr = c"EvTbNode";
+
+    fn from(event: &Self::CType) -> TableNodeEvent {
+        TableNodeEvent {
+            parent: TreeNode::from_ptr(event.parent),
+            index: event.ichild,
         }
     }
 }
 
+/// Information of a node in a table in tree mode.
+pub struct TableNodeInfoEvent {
+    /// Node.
+    pub node: TreeNode,
+    /// The node has children.
+    pub children: bool,
+    /// The node is expanded.
+    pub expanded: bool,
+}
+
+impl NappGUIEventResult for TableNodeInfoEvent {
+    type CType = EvTbNodeInfo;
+    const TYPE: &'static CStr = c"EvTbNodeInfo";
+
+    fn to(&self) -> Self::CType {
+        EvTbNodeInfo {
+            node: self.node.as_ptr(),
+            children: self.children as _,
+            expanded: self.expanded as _,
+        }
+    }
+}
+
+/// Parameters of the OnExpand event of a table in tree mode.
+pub struct TableExpandEvent {
+    /// Node expanded or collapsed.
+    pub node: TreeNode,
+    /// The node has been expanded (true) or collapsed (false).
+    pub expanded: bool,
+}
+
+impl NappGUIEventParams for TableExpandEvent {
+    type CType = EvTbExpand;
+    const TYPE: &'static CStr = c"EvTbExpand";
+
+    fn from(event: &Self::CType) -> TableExpandEvent {
+        TableExpandEvent {
+            node: TreeNode::from_ptr(event.node).expect("Expand event without node"),
+            expanded: event.expanded != 0,
+        }
+    }
+}
+
+/// Parameters of the OnChange event of an object bound to a layout.
+pub struct DBindChangeEvent {
+    /// Name of the modified field.
+    pub field: String,
+}
+
 /// Implement for core types.
 impl NappGUIEventParams for bool {
     type CType = bool;
@@ -5,7 +5,13 @@ use std::{
 
 use crate::{
     draw_2d::{Color, Font},
-    gui::{define_object, event::MouseEvent, listener, Callback},
+    gui::{
+        define_object,
+        event::MouseEvent,
+        listener,
+        locale::{forget_localized_text, set_localized_text, Localized},
+        Callback,
+    },
     types::{Align, Ellipsis, FontStyle},
 };
 
@@ -49,12 +55,21 @@ impl Label {
 
     /// Set the text that the label will display.
     pub fn set_text(&self, text: &str) {
+        forget_localized_text(Localized::Label(*self));
         let text = CString::new(text).unwrap();
         unsafe {
             label_text(self.as_ptr(), text.as_ptr());
         }
     }
 
+    /// Set the text of the label from a resource.
+    ///
+    /// # Remarks
+    /// The text is set again when the language changes with gui_language.
+    pub fn set_text_rid(&self, rid: &str) {
+        set_localized_text(Localized::Label(*self), rid);
+    }
+
     /// Set the text with which the control will be sized.
     ///
     /// # Remarks
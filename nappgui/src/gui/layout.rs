@@ -1,17 +1,19 @@
-use std::{cell::RefCell, ffi::CString};
+use std::{cell::RefCell, ffi::CString, rc::Rc};
 
 use nappgui_sys::{
     cell_dbind_imp, cell_empty, cell_enabled, cell_padding4, cell_visible, dbind_create_imp, dbind_destroy_imp,
-    layout_bgcolor, layout_cell, layout_control, layout_create, layout_dbind_imp, layout_dbind_obj_imp, layout_group,
-    layout_halign, layout_hexpandn, layout_hmargin, layout_hsize, layout_insert_col, layout_insert_row, layout_margin4,
-    layout_ncols, layout_nrows, layout_panel_replace, layout_remove_col, layout_remove_row, layout_show_col,
-    layout_show_row, layout_skcolor, layout_taborder, layout_tabstop, layout_update, layout_valign, layout_vexpandn,
-    layout_vmargin, layout_vsize,
+    evbind_modify_imp, layout_bgcolor, layout_cell, layout_control, layout_create, layout_dbind_get_obj_imp,
+    layout_dbind_imp, layout_dbind_obj_imp, layout_group, layout_halign, layout_hexpandn, layout_hmargin, layout_hsize,
+    layout_insert_col, layout_insert_row, layout_margin4, layout_ncols, layout_nrows, layout_panel_replace,
+    layout_remove_col, layout_remove_row, layout_show_col, layout_show_row, layout_skcolor, layout_taborder,
+    layout_tabstop, layout_update, layout_valign, layout_vexpandn, layout_vmargin, layout_vsize,
 };
 
 use crate::{
-    core::dbind::dbind_struct,
+    core::{dbind::dbind_struct, DataBind},
     draw_2d::Color,
+    error::{NappguiError, NappguiErrorKind},
+    gui::{event::DBindChangeEvent, locale::forget_localized_cells},
     types::{Align, GuiOrient},
 };
 
@@ -21,6 +23,7 @@ use super::*;
 pub(crate) struct LayoutProps {
     object_type: RefCell<Option<CString>>,
     object: RefCell<Option<*mut ()>>,
+    on_dbind_change: Callback<DBindChangeEvent>,
 }
 
 define_object!(Layout, LayoutInner, Layout, LayoutProps);
@@ -137,6 +140,8 @@ impl Layout {
     pub fn remove_column(&self, col: u32) {
         assert!(col < self.ncols());
 
+        // The destroyed buttons and labels must not be relabeled when the language changes.
+        forget_localized_cells(*self, (0..self.nrows()).map(|row| (col, row)));
         unsafe { layout_remove_col(self.as_ptr(), col) };
     }
 
@@ -151,6 +156,7 @@ impl Layout {
     pub fn remove_row(&self, row: u32) {
         assert!(row < self.nrows());
 
+        forget_localized_cells(*self, (0..self.ncols()).map(|col| (col, row)));
         unsafe { layout_remove_row(self.as_ptr(), row) };
     }
 
@@ -327,9 +333,39 @@ impl Layout {
     /// Bind a struct within a layout.
     pub fn dbind(&self, ty: &str) {
         let ty = CString::new(ty).unwrap();
+        let listener = listener!(self.as_ptr(), Layout, |layout, event| {
+            let Some((Some(f), Some(ty))) = layout.inner(|inner| {
+                (
+                    inner.props.on_dbind_change.borrow().clone(),
+                    inner.props.object_type.borrow().clone(),
+                )
+            }) else {
+                return;
+            };
+            let field = dbind_struct(&ty, |dbind| {
+                dbind.fields.borrow().iter().find_map(|field| {
+                    let modified = unsafe {
+                        evbind_modify_imp(
+                            event.inner,
+                            dbind.ty.as_ptr(),
+                            dbind.size,
+                            field.name.as_ptr(),
+                            field.ty.as_ptr(),
+                            field.offset,
+                            field.size,
+                        )
+                    };
+                    (modified != 0).then(|| field.name.to_string_lossy().into_owned())
+                })
+            });
+            if let Some(Some(field)) = field {
+                let params = DBindChangeEvent { field };
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&params)));
+            }
+        });
         self.inner(|inner| {
             dbind_struct(&ty, |obj| unsafe {
-                layout_dbind_imp(inner.ptr.get(), std::ptr::null_mut(), obj.ty.as_ptr(), obj.size);
+                layout_dbind_imp(inner.ptr.get(), listener, obj.ty.as_ptr(), obj.size);
             });
             let object = unsafe { dbind_create_imp(ty.as_ptr()) };
             unsafe { layout_dbind_obj_imp(inner.ptr.get(), object as _, ty.as_ptr()) };
@@ -338,6 +374,53 @@ impl Layout {
         });
     }
 
+    /// Notify each time the user edits a field of the object bound with dbind.
+    pub fn set_on_dbind_change_handler<F>(&self, handler: F)
+    where
+        F: Fn(&DBindChangeEvent) + 'static,
+    {
+        self.inner(|inner| *inner.props.on_dbind_change.borrow_mut() = Some(Rc::new(handler)));
+    }
+
+    /// Pointer to the bound object, if its type is T.
+    fn dbind_ptr<T>(&self) -> Option<*mut T>
+    where
+        T: DataBind,
+    {
+        self.inner(|inner| {
+            let ty = inner.props.object_type.borrow();
+            if ty.as_deref() != Some(T::TYPE) {
+                return None;
+            }
+            let object = unsafe { layout_dbind_get_obj_imp(inner.ptr.get(), T::TYPE.as_ptr()) };
+            (!object.is_null()).then_some(object as *mut T)
+        })
+        .flatten()
+    }
+
+    /// Gets a copy of the object bound with dbind, with the values edited in the controls.
+    /// Returns None if the bound object is not of type T.
+    pub fn dbind_object<T>(&self) -> Option<T>
+    where
+        T: DataBind + Clone,
+    {
+        self.dbind_ptr::<T>().map(|object| unsafe { (*object).clone() })
+    }
+
+    /// Replaces the object bound with dbind and updates the controls with its values.
+    pub fn dbind_set<T>(&self, value: &T) -> Result<(), NappguiError>
+    where
+        T: DataBind + Clone,
+    {
+        let object = self
+            .dbind_ptr::<T>()
+            .ok_or(NappguiError::Internal(NappguiErrorKind::WrongTypeTransmute))?;
+        // The previous values are released by its own destructors.
+        drop(unsafe { std::ptr::replace(object, value.clone()) });
+        unsafe { layout_dbind_obj_imp(self.as_ptr(), object as _, T::TYPE.as_ptr()) };
+        Ok(())
+    }
+
     /// Bind a field to a layout cell.
     pub fn dbind_cell(&self, col: u32, row: u32, field: &str) {
         let cell = unsafe { layout_cell(self.as_ptr(), col, row) };
@@ -345,7 +428,7 @@ impl Layout {
         self.inner(|layout| {
             if let Some(ty) = layout.props.object_type.borrow().as_ref() {
                 dbind_struct(ty, |dbind| {
-                    if let Some(field) = dbind.fields.borrow().get(&field) {
+                    if let Some(field) = dbind.fields.borrow().iter().find(|dbind_field| dbind_field.name == field) {
                         unsafe {
                             cell_dbind_imp(
                                 cell,
@@ -423,6 +506,7 @@ impl_layout!(Progress, layout_progress);
 impl_layout!(Slider, layout_slider);
 impl_layout!(SplitView, layout_splitview);
 impl_layout!(TableView, layout_tableview);
+impl_layout!(Tabs, layout_tabs);
 impl_layout!(TextView, layout_textview);
 impl_layout!(UpDown, layout_updown);
 impl_layout!(View, layout_view);
@@ -0,0 +1,180 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+use nappgui_sys::{
+    guicontrol_get_window, layout_get_button, layout_get_label, layout_get_layout, layout_get_panel,
+    layout_get_splitview, layout_ncols, layout_nrows, window_update,
+};
+
+use crate::gui::{gui_text, Button, Label, Layout, Menu, MenuItem, Panel, SplitView, Window, GUID};
+
+/// An object whose text comes from a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Localized {
+    /// The text of a button.
+    Button(Button),
+    /// The text of a label.
+    Label(Label),
+    /// The text of a menu item.
+    MenuItem(MenuItem),
+    /// The title of a window.
+    WindowTitle(Window),
+}
+
+thread_local! {
+    /// The resource ID of the text of each localized object.
+    static LOCALIZED: RefCell<HashMap<Localized, String>> = Default::default();
+}
+
+impl Localized {
+    fn set_text(self, text: &str) {
+        match self {
+            Localized::Button(button) => button.set_text(text),
+            Localized::Label(label) => label.set_text(text),
+            Localized::MenuItem(item) => item.set_text(text),
+            Localized::WindowTitle(window) => {
+                // Destroyed windows have a null pointer.
+                if !window.as_ptr().is_null() {
+                    window.set_title(text);
+                }
+            }
+        }
+    }
+
+    /// The pointer of the object.
+    fn ptr(self) -> *mut std::ffi::c_void {
+        match self {
+            Localized::Button(button) => button.as_ptr() as _,
+            Localized::Label(label) => label.as_ptr() as _,
+            Localized::MenuItem(item) => item.as_ptr() as _,
+            Localized::WindowTitle(window) => window.as_ptr() as _,
+        }
+    }
+
+    /// The window that contains the object. Can be null.
+    fn window(self) -> *mut nappgui_sys::Window {
+        match self {
+            Localized::Button(button) => unsafe { guicontrol_get_window(button.as_ptr() as _) },
+            Localized::Label(label) => unsafe { guicontrol_get_window(label.as_ptr() as _) },
+            Localized::MenuItem(_) => std::ptr::null_mut(),
+            Localized::WindowTitle(window) => window.as_ptr(),
+        }
+    }
+}
+
+/// Sets the text of an object from a resource, and remembers the resource to set the text again
+/// when the language changes.
+pub(crate) fn set_localized_text(target: Localized, rid: &str) {
+    target.set_text(&gui_text(rid));
+    LOCALIZED.with_borrow_mut(|localized| localized.insert(target, rid.to_string()));
+}
+
+/// Forgets the resource of an object, because its text has been set without a resource.
+pub(crate) fn forget_localized_text(target: Localized) {
+    LOCALIZED.with_borrow_mut(|localized| localized.remove(&target));
+}
+
+/// Forgets the resources of the objects in some cells of a layout, because the cells are going to
+/// be destroyed. The sub-layouts, panels and split views in the cells are searched too.
+pub(crate) fn forget_localized_cells(layout: Layout, cells: impl IntoIterator<Item = (u32, u32)>) {
+    let mut objects = HashSet::new();
+    for (col, row) in cells {
+        cell_objects(layout.as_ptr(), col, row, &mut objects);
+    }
+    forget_localized_objects(&objects);
+}
+
+/// Forgets the resources of a menu item and of the items of its submenus, because the item is going
+/// to be destroyed.
+pub(crate) fn forget_localized_item(item: MenuItem) {
+    let mut objects = HashSet::new();
+    item_objects(item, &mut objects);
+    forget_localized_objects(&objects);
+}
+
+fn forget_localized_objects(objects: &HashSet<*mut std::ffi::c_void>) {
+    LOCALIZED.with_borrow_mut(|localized| localized.retain(|target, _| !objects.contains(&target.ptr())));
+}
+
+/// Adds the buttons and labels in a cell of a layout, recursively.
+fn cell_objects(layout: *mut nappgui_sys::Layout, col: u32, row: u32, objects: &mut HashSet<*mut std::ffi::c_void>) {
+    // The getters return null if the cell contains another kind of object.
+    let button = unsafe { layout_get_button(layout, col, row) };
+    let label = unsafe { layout_get_label(layout, col, row) };
+    for object in [button as *mut std::ffi::c_void, label as _] {
+        if !object.is_null() {
+            objects.insert(object);
+        }
+    }
+
+    let sublayout = unsafe { layout_get_layout(layout, col, row) };
+    if !sublayout.is_null() {
+        layout_objects(sublayout, objects);
+    }
+    let panel = unsafe { layout_get_panel(layout, col, row) };
+    if !panel.is_null() {
+        panel_objects(Panel(panel as GUID), objects);
+    }
+    let split = unsafe { layout_get_splitview(layout, col, row) };
+    if !split.is_null() {
+        split_objects(SplitView(split as GUID), objects);
+    }
+}
+
+fn layout_objects(layout: *mut nappgui_sys::Layout, objects: &mut HashSet<*mut std::ffi::c_void>) {
+    let (ncols, nrows) = unsafe { (layout_ncols(layout), layout_nrows(layout)) };
+    for row in 0..nrows {
+        for col in 0..ncols {
+            cell_objects(layout, col, row, objects);
+        }
+    }
+}
+
+fn panel_objects(panel: Panel, objects: &mut HashSet<*mut std::ffi::c_void>) {
+    let layouts = (0..).map_while(|index| panel.layout(index));
+    for layout in layouts {
+        layout_objects(layout.as_ptr(), objects);
+    }
+}
+
+fn split_objects(split: SplitView, objects: &mut HashSet<*mut std::ffi::c_void>) {
+    let (panels, splits) = split.children();
+    for panel in panels {
+        panel_objects(panel, objects);
+    }
+    for split in splits {
+        split_objects(split, objects);
+    }
+}
+
+fn item_objects(item: MenuItem, objects: &mut HashSet<*mut std::ffi::c_void>) {
+    objects.insert(item.as_ptr() as _);
+    if let Some(menu) = item.submenu() {
+        menu_objects(menu, objects);
+    }
+}
+
+fn menu_objects(menu: Menu, objects: &mut HashSet<*mut std::ffi::c_void>) {
+    for item in menu.items() {
+        item_objects(item, objects);
+    }
+}
+
+/// Sets again the texts of the localized objects after a language change, and updates their
+/// windows, since the size of the texts changes.
+pub(crate) fn relabel() {
+    // The setters forget the resources, so they are restored after setting the texts.
+    let localized = LOCALIZED.take();
+    let mut windows = HashSet::new();
+    for (target, rid) in &localized {
+        target.set_text(&gui_text(rid));
+        windows.insert(target.window());
+    }
+    LOCALIZED.with_borrow_mut(|current| current.extend(localized));
+
+    for window in windows.into_iter().filter(|window| !window.is_null()) {
+        unsafe { window_update(window) };
+    }
+}
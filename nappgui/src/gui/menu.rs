@@ -5,7 +5,7 @@ use nappgui_sys::{
     menu_off_items, V2Df,
 };
 
-use crate::gui::{define_object, Window};
+use crate::gui::{define_object, locale::forget_localized_item, Window};
 
 use super::MenuItem;
 
@@ -22,6 +22,12 @@ impl Menu {
         self.inner(|x| x.props.c_managed.set(managed));
     }
 
+    /// The items of the menu, in order.
+    pub(crate) fn items(&self) -> Vec<MenuItem> {
+        self.inner(|inner| inner.props.items.borrow().clone())
+            .unwrap_or_default()
+    }
+
     pub(crate) fn destroy(&self) {
         self.inner(|inner| {
             if !inner.props.c_managed.get() {
@@ -57,6 +63,9 @@ impl Menu {
     /// The element will be destroyed and cannot be reused. If has a submenu associated,
     /// it will also be destroyed recursively.
     pub fn delete_item(&self, index: u32) {
+        if let Some(&item) = self.items().get(index as usize) {
+            forget_localized_item(item);
+        }
         unsafe { menu_del_item(self.as_ptr(), index) };
         self.inner(|inner| inner.props.items.borrow_mut().remove(index as _));
     }
@@ -2,7 +2,13 @@ use std::{cell::RefCell, ffi::CStr, rc::Rc};
 
 use crate::{
     draw_2d::Image,
-    gui::{define_object, event::MenuEvent, listener, Callback, Menu},
+    gui::{
+        define_object,
+        event::MenuEvent,
+        listener,
+        locale::{forget_localized_text, set_localized_text, Localized},
+        Callback, Menu,
+    },
     types::{GuiState, KeyCode, ModifierKey},
 };
 
@@ -54,10 +60,19 @@ impl MenuItem {
 
     /// Set the item text.
     pub fn set_text(&self, text: &str) {
+        forget_localized_text(Localized::MenuItem(*self));
         let text = std::ffi::CString::new(text).unwrap();
         unsafe { menuitem_text(self.as_ptr(), text.as_ptr()) };
     }
 
+    /// Set the item text from a resource.
+    ///
+    /// # Remarks
+    /// The text is set again when the language changes with gui_language.
+    pub fn set_text_rid(&self, rid: &str) {
+        set_localized_text(Localized::MenuItem(*self), rid);
+    }
+
     /// Set the icon that will display the item.
     pub fn set_image(&self, image: &Image) {
         unsafe { menuitem_image(self.as_ptr(), image.as_ptr()) };
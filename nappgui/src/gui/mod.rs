@@ -7,6 +7,7 @@ mod label;
 mod layout;
 mod line;
 mod listbox;
+mod locale;
 mod menu;
 mod menuitem;
 mod object;
@@ -15,7 +16,10 @@ mod popup;
 mod progress;
 mod slider;
 mod splitview;
+mod tablemodel;
 mod tableview;
+mod tabletree;
+mod tabs;
 mod textview;
 mod updown;
 mod view;
@@ -25,14 +29,17 @@ mod window;
 /// Events are messages that are generated by the user interface and that can be handled by the
 pub mod event;
 
+/// Runtime loader of user interfaces declared in XML, like the `nappgui_ui!` macro.
+pub mod ui;
+
 use crate::{
     draw_2d::{Image, Point2D},
     types::GuiTab,
 };
 pub use {
     button::*, combo::*, control::*, edit::*, imageview::*, label::*, layout::*, line::*, listbox::*, menu::*,
-    menuitem::*, object::*, panel::*, popup::*, progress::*, slider::*, splitview::*, tableview::*, textview::*,
-    updown::*, view::*, webview::*, window::*,
+    menuitem::*, object::*, panel::*, popup::*, progress::*, slider::*, splitview::*, tablemodel::*, tableview::*, tabletree::*, tabs::*,
+    textview::*, updown::*, view::*, webview::*, window::*,
 };
 
 /// Get image from resource
@@ -72,11 +79,16 @@ pub fn gui_respack(handler: NappguiResourceHandler) {
 }
 
 /// Set the language of the registered resources with gui_respack.
+///
+/// # Remarks
+/// The texts set from resources with `set_text_rid` or `set_title_rid` are set again in the new
+/// language, and their windows are updated.
 pub fn gui_language(language: &str) {
     let language = std::ffi::CString::new(language).unwrap();
     unsafe {
         nappgui_sys::gui_language(language.as_ptr());
     }
+    locale::relabel();
 }
 
 /// Returns the position of the mouse cursor.
@@ -102,9 +102,9 @@ where
     }
 }
 
-// pub(crate) fn global_object_remove<T, F, R>(uid: GUID) {
-//     GLOBAL_OBJECTS.with_borrow_mut(|objects| objects.remove(&uid));
-// }
+pub(crate) fn global_object_remove(uid: GUID) {
+    GLOBAL_OBJECTS.with_borrow_mut(|objects| objects.remove(&uid));
+}
 
 macro_rules! listener {
     ($ptr: expr, $type:ident, $member:ident($($params: ty)?) $(-> $return:ty)?) => {{
@@ -124,6 +124,20 @@ macro_rules! listener {
             }
         }
 
+        let listener = unsafe { nappgui_sys::listener_imp($ptr as _, Some(shim)) };
+        listener
+    }};
+    // Listeners that need the object and the whole event, like the data of a table.
+    ($ptr: expr, $type:ident, |$object:ident, $event:ident| $body:block) => {{
+        #[allow(unused)]
+        extern "C" fn shim(obj: *mut std::ffi::c_void, event: *mut nappgui_sys::Event) {
+            // The objects are registered with their pointer as GUID, see from_raw.
+            let $object = $type(obj as crate::gui::GUID);
+            let $event = crate::core::event::Event::new(event);
+            // The body calls user code, which must not unwind into C.
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body));
+        }
+
         let listener = unsafe { nappgui_sys::listener_imp($ptr as _, Some(shim)) };
         listener
     }};
@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+
 use nappgui_sys::{
     splitview_get_pos, splitview_horizontal, splitview_minsize0, splitview_minsize1, splitview_panel, splitview_pos,
     splitview_splitview, splitview_tableview, splitview_textview, splitview_vertical, splitview_view,
@@ -10,7 +12,10 @@ use crate::{
 };
 
 #[derive(Default)]
-pub(crate) struct SplitViewProps {}
+pub(crate) struct SplitViewProps {
+    panels: RefCell<Vec<Panel>>,
+    splits: RefCell<Vec<SplitView>>,
+}
 
 define_object!(SplitView, SplitViewInner, SplitView, SplitViewProps);
 
@@ -37,6 +42,12 @@ impl SplitView {
         control.insert_into_splitview(self, tabstop);
     }
 
+    /// The panels and split views added as children.
+    pub(crate) fn children(&self) -> (Vec<Panel>, Vec<SplitView>) {
+        self.inner(|inner| (inner.props.panels.borrow().clone(), inner.props.splits.borrow().clone()))
+            .unwrap_or_default()
+    }
+
     /// Sets the position of the view separator.
     pub fn set_position(&self, mode: SplitMode, pos: f32) {
         unsafe { splitview_pos(self.as_ptr(), mode as _, pos) }
@@ -94,13 +105,15 @@ impl SplitViewInsertChildTrait for WebView {
 
 impl SplitViewInsertChildTrait for SplitView {
     fn insert_into_splitview(&self, split_view: &SplitView, _tabstop: bool) {
-        unsafe { splitview_splitview(split_view.as_ptr(), self.as_ptr()) }
+        unsafe { splitview_splitview(split_view.as_ptr(), self.as_ptr()) };
+        split_view.inner(|inner| inner.props.splits.borrow_mut().push(*self));
     }
 }
 
 impl SplitViewInsertChildTrait for Panel {
     fn insert_into_splitview(&self, split_view: &SplitView, _tabstop: bool) {
-        unsafe { splitview_panel(split_view.as_ptr(), self.as_ptr()) }
+        unsafe { splitview_panel(split_view.as_ptr(), self.as_ptr()) };
+        split_view.inner(|inner| inner.props.panels.borrow_mut().push(*self));
     }
 }
 
@@ -0,0 +1,81 @@
+use std::cmp::Ordering;
+
+use crate::{
+    draw_2d::{Font, Image},
+    types::Align,
+};
+
+/// Data source of a TableView. See TableView::set_model.
+///
+/// # Remarks
+/// The table reads the model each time it needs to draw. After modifying the model,
+/// TableView::update must be called to refresh the view.
+pub trait TableModel {
+    /// Number of rows of the table.
+    fn num_rows(&self) -> u32;
+
+    /// Text of a cell.
+    fn cell_text(&self, row: u32, column: u32) -> String;
+
+    /// Text alignment of a cell.
+    fn cell_align(&self, _row: u32, _column: u32) -> Align {
+        Align::Left
+    }
+
+    /// Icon of a cell. The column must reserve space for icons with TableView::set_column_icon.
+    fn cell_icon(&self, _row: u32, _column: u32) -> Option<Image> {
+        None
+    }
+
+    /// Compares two rows by the values of a column, to sort the table. By default, the text of
+    /// the cells is compared.
+    fn compare(&self, column: u32, row1: u32, row2: u32) -> Ordering {
+        self.cell_text(row1, column).cmp(&self.cell_text(row2, column))
+    }
+
+    /// Font of the table.
+    ///
+    /// # Remarks
+    /// The native control does not support fonts or colors per cell, so the font is applied to
+    /// the entire table when the model is set.
+    fn font(&self) -> Option<Font> {
+        None
+    }
+}
+
+/// Description of a column of a TableView with a model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableColumn {
+    /// Header text.
+    pub title: String,
+    /// Column width.
+    pub width: f32,
+    /// Header text alignment.
+    pub align: Align,
+    /// Clicking the header sorts the table by this column.
+    pub sortable: bool,
+}
+
+impl TableColumn {
+    /// Create a column description.
+    pub fn new(title: &str, width: f32) -> Self {
+        Self {
+            title: title.to_string(),
+            width,
+            align: Align::Left,
+            sortable: false,
+        }
+    }
+
+    /// Sets the header text alignment.
+    pub fn with_align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets whether the table can be sorted by this column.
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
@@ -0,0 +1,169 @@
+use std::ffi::c_void;
+
+/// Identifier of a node in a TableTree.
+///
+/// # Remarks
+/// Node identifiers are stable, they do not change when other nodes are added or removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TreeNode(u32);
+
+impl TreeNode {
+    /// The node handle used by the table view. It can never be null.
+    pub(crate) fn as_ptr(&self) -> *mut c_void {
+        (self.0 as usize + 1) as *mut c_void
+    }
+
+    pub(crate) fn from_ptr(ptr: *mut c_void) -> Option<Self> {
+        if ptr.is_null() {
+            return None;
+        }
+        Some(Self((ptr as usize - 1) as u32))
+    }
+}
+
+struct TreeNodeData {
+    parent: Option<TreeNode>,
+    children: Vec<TreeNode>,
+    cells: Vec<String>,
+    expanded: bool,
+}
+
+/// Hierarchical data model of a TableView in tree mode. Each node is a row of the table and
+/// stores the text of each column.
+///
+/// # Remarks
+/// See TableView::set_tree. After modifying the tree of a table, TableView::update must be called.
+#[derive(Default)]
+pub struct TableTree {
+    nodes: Vec<Option<TreeNodeData>>,
+    roots: Vec<TreeNode>,
+}
+
+impl TableTree {
+    /// Create an empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn data(&self, node: TreeNode) -> Option<&TreeNodeData> {
+        self.nodes.get(node.0 as usize).and_then(|data| data.as_ref())
+    }
+
+    fn data_mut(&mut self, node: TreeNode) -> Option<&mut TreeNodeData> {
+        self.nodes.get_mut(node.0 as usize).and_then(|data| data.as_mut())
+    }
+
+    /// Adds a node at the end of the children of parent. If parent is None, the node is added
+    /// to the first level of the tree.
+    ///
+    /// # Panics
+    /// If parent is not a node of the tree.
+    pub fn add_node(&mut self, parent: Option<TreeNode>, cells: &[&str]) -> TreeNode {
+        let count = self.children(parent).len();
+        self.insert_node(parent, count, cells)
+    }
+
+    /// Inserts a node in a position of the children of parent.
+    ///
+    /// # Panics
+    /// If parent is not a node of the tree or the position is out of range.
+    pub fn insert_node(&mut self, parent: Option<TreeNode>, index: usize, cells: &[&str]) -> TreeNode {
+        let node = TreeNode(self.nodes.len() as u32);
+        let siblings = match parent {
+            Some(parent) => &mut self.data_mut(parent).expect("Invalid parent node").children,
+            None => &mut self.roots,
+        };
+        siblings.insert(index, node);
+        self.nodes.push(Some(TreeNodeData {
+            parent,
+            children: Vec::new(),
+            cells: cells.iter().map(|cell| cell.to_string()).collect(),
+            expanded: false,
+        }));
+        node
+    }
+
+    /// Removes a node and all its descendants.
+    pub fn remove_node(&mut self, node: TreeNode) {
+        let Some(data) = self.data(node) else {
+            return;
+        };
+        let siblings = match data.parent {
+            Some(parent) => &mut self.data_mut(parent).unwrap().children,
+            None => &mut self.roots,
+        };
+        siblings.retain(|child| *child != node);
+
+        let mut pending = vec![node];
+        while let Some(node) = pending.pop() {
+            if let Some(data) = self.nodes[node.0 as usize].take() {
+                pending.extend(data.children);
+            }
+        }
+    }
+
+    /// Removes all nodes.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.roots.clear();
+    }
+
+    /// Checks if a node belongs to the tree.
+    pub fn contains(&self, node: TreeNode) -> bool {
+        self.data(node).is_some()
+    }
+
+    /// Gets the children of a node. If node is None, returns the nodes of the first level.
+    pub fn children(&self, node: Option<TreeNode>) -> &[TreeNode] {
+        match node {
+            Some(node) => self.data(node).map(|data| data.children.as_slice()).unwrap_or(&[]),
+            None => &self.roots,
+        }
+    }
+
+    /// Gets the parent of a node. Returns None for the nodes of the first level.
+    pub fn parent(&self, node: TreeNode) -> Option<TreeNode> {
+        self.data(node).and_then(|data| data.parent)
+    }
+
+    /// Gets the depth of a node. The nodes of the first level have depth 0.
+    pub fn depth(&self, node: TreeNode) -> u32 {
+        let mut depth = 0;
+        let mut current = self.parent(node);
+        while let Some(parent) = current {
+            depth += 1;
+            current = self.parent(parent);
+        }
+        depth
+    }
+
+    /// Sets the text of a column of the node.
+    pub fn set_cell(&mut self, node: TreeNode, column: u32, text: &str) {
+        if let Some(data) = self.data_mut(node) {
+            let column = column as usize;
+            if data.cells.len() <= column {
+                data.cells.resize(column + 1, String::new());
+            }
+            data.cells[column] = text.to_string();
+        }
+    }
+
+    /// Gets the text of a column of the node.
+    pub fn cell(&self, node: TreeNode, column: u32) -> Option<&str> {
+        self.data(node)
+            .and_then(|data| data.cells.get(column as usize))
+            .map(|cell| cell.as_str())
+    }
+
+    /// Expands or collapses a node.
+    pub fn set_expanded(&mut self, node: TreeNode, expanded: bool) {
+        if let Some(data) = self.data_mut(node) {
+            data.expanded = expanded;
+        }
+    }
+
+    /// Checks if a node is expanded.
+    pub fn is_expanded(&self, node: TreeNode) -> bool {
+        self.data(node).map(|data| data.expanded).unwrap_or(false)
+    }
+}
@@ -1,19 +1,28 @@
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    draw_2d::Font, gui::{
-        Callback, VoidCallback, define_object, event::{TableDataParams, TableDataResult, TablePositionEvent}, listener,
-    }, types::{Align, EventType},
+    draw_2d::Font,
+    gui::{
+        define_object,
+        event::{
+            ButtonEvent, TableCellEvent, TableDataParams, TableDataResult, TableExpandEvent, TableNodeEvent,
+            TableNodeInfoEvent, TablePositionEvent,
+        },
+        listener, Callback, TableColumn, TableModel, TableTree, TreeNode, VoidCallback,
+    },
+    types::{Align, EventType, Indicator},
 };
 
 use nappgui_sys::{
     tableview_OnData, tableview_OnHeaderClick, tableview_OnRowClick, tableview_OnSelect, tableview_add_column_text,
-    tableview_column_align, tableview_column_freeze, tableview_column_icon, tableview_column_limits,
-    tableview_column_resizable, tableview_column_width, tableview_create, tableview_del_column, tableview_deselect,
-    tableview_deselect_all, tableview_focus_row, tableview_font, tableview_get_focus_row, tableview_grid,
-    tableview_header_align, tableview_header_clickable, tableview_header_height, tableview_header_resizable,
-    tableview_header_title, tableview_header_visible, tableview_hkey_scroll, tableview_multisel, tableview_row_height,
-    tableview_scroll_visible, tableview_select, tableview_selected, tableview_size, tableview_update, S2Df,
+    tableview_column_align, tableview_column_count, tableview_column_freeze, tableview_column_icon,
+    tableview_column_limits, tableview_column_resizable, tableview_column_width, tableview_create,
+    tableview_del_column, tableview_deselect, tableview_deselect_all, tableview_focus_row, tableview_font,
+    tableview_get_focus_row, tableview_grid, tableview_header_align, tableview_header_clickable,
+    tableview_header_height, tableview_header_indicator, tableview_header_resizable, tableview_header_title,
+    tableview_header_visible, tableview_hkey_scroll, tableview_multisel, tableview_node_row, tableview_row_height,
+    tableview_row_node, tableview_scroll_visible, tableview_select, tableview_selected, tableview_size, tableview_tree,
+    tableview_update, S2Df,
 };
 
 #[derive(Default)]
@@ -22,6 +31,41 @@ pub(crate) struct TableViewProps {
     on_row_click: VoidCallback,
     on_header_click: VoidCallback,
     on_data: Callback<TableDataParams, TableDataResult>,
+    on_expand: Callback<TableExpandEvent>,
+    tree: RefCell<Option<TableTree>>,
+    model: RefCell<Option<Rc<RefCell<dyn TableModel>>>>,
+    sort: RefCell<TableSort>,
+}
+
+/// Sorting state of a table with a model.
+#[derive(Default)]
+struct TableSort {
+    /// Columns that can be sorted.
+    sortable: Vec<u32>,
+    /// Sort column and ascending order.
+    column: Option<(u32, bool)>,
+    /// Model row shown in each table row.
+    rows: Vec<u32>,
+}
+
+impl TableSort {
+    fn apply(&mut self, model: &dyn TableModel) {
+        self.rows = (0..model.num_rows()).collect();
+        if let Some((column, ascending)) = self.column {
+            self.rows.sort_by(|row1, row2| {
+                let order = model.compare(column, *row1, *row2);
+                if ascending {
+                    order
+                } else {
+                    order.reverse()
+                }
+            });
+        }
+    }
+
+    fn model_row(&self, row: u32) -> u32 {
+        self.rows.get(row as usize).copied().unwrap_or(row)
+    }
 }
 
 define_object!(TableView, TableViewInner, TableView, TableViewProps);
@@ -58,7 +102,34 @@ impl TableView {
         F: Fn() + 'static,
     {
         self.inner(|inner| *inner.props.on_header_click.borrow_mut() = Some(Rc::new(handler)));
-        let listener = listener!(self.as_ptr(), TableViewInner, on_header_click());
+        self.set_header_click_listener();
+    }
+
+    /// The header click also sorts the tables with a model, so the same listener is shared
+    /// by set_on_header_click_handler and set_model.
+    fn set_header_click_listener(&self) {
+        let listener = listener!(self.as_ptr(), TableView, |view, event| {
+            let column = unsafe { event.params::<ButtonEvent>() }.index;
+            let sort = view
+                .inner(|inner| {
+                    let sort = inner.props.sort.borrow();
+                    if !sort.sortable.contains(&column) {
+                        return None;
+                    }
+                    match sort.column {
+                        Some((current, ascending)) if current == column => Some(!ascending),
+                        _ => Some(true),
+                    }
+                })
+                .flatten();
+            if let Some(ascending) = sort {
+                view.sort(column, ascending);
+            }
+
+            if let Some(Some(f)) = view.inner(|inner| inner.props.on_header_click.borrow().clone()) {
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f()));
+            }
+        });
         unsafe { tableview_OnHeaderClick(self.as_ptr(), listener) }
     }
 
@@ -69,37 +140,263 @@ impl TableView {
     {
         self.inner(|inner| *inner.props.on_data.borrow_mut() = Some(Rc::new(handler)));
 
-        let listener = {
-            use std::ffi::c_void;
-            extern "C" fn shim(obj: *mut c_void, event: *mut nappgui_sys::Event) {
-                if let Some(Some(f)) =
-                    crate::gui::global_object(obj as _, |inner: &TableViewInner| inner.props.on_data.borrow().clone())
+        let listener = listener!(self.as_ptr(), TableView, |view, event| {
+            if let Some(Some(f)) = view.inner(|inner| inner.props.on_data.borrow().clone()) {
+                let params = match event.type_() {
+                    EventType::TableNRows => TableDataParams::TableNCols,
+                    EventType::TableCell => TableDataParams::TableCell(unsafe { event.params::<TablePositionEvent>() }),
+                    _ => {
+                        return;
+                    }
+                };
+                if let Ok(r) =
+                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&(params as TableDataParams))))
                 {
-                    let event = crate::core::event::Event::new(event);
-                    let params = match event.type_() {
-                        EventType::TableNRows => TableDataParams::TableNCols,
-                        EventType::TableCell => {
-                            TableDataParams::TableCell(unsafe { event.params::<TablePositionEvent>() })
-                        }
-                        _ => {
-                            return;
-                        }
-                    };
-                    if let Ok(r) =
-                        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&(params as TableDataParams))))
-                    {
-                        match r {
-                            TableDataResult::TableNCols(n) => unsafe { event.result(n) },
-                            TableDataResult::TableCell(table_cell_event) => unsafe { event.result(table_cell_event) },
+                    match r {
+                        TableDataResult::TableNCols(n) => unsafe { event.result(n) },
+                        TableDataResult::TableCell(table_cell_event) => unsafe { event.result(table_cell_event) },
+                    }
+                }
+            }
+        });
+
+        unsafe { tableview_OnData(self.as_ptr(), listener) }
+    }
+
+    /// Connects the table to a data model. A column is added to the table for each description.
+    ///
+    /// # Remarks
+    /// The columns of the table are replaced, so the model can be changed by calling this
+    /// function again.
+    /// The model replaces the handler set with set_on_data_handler. Keep a clone of the model
+    /// to modify it and call update afterwards. The model must not be borrowed during update.
+    /// Clicking the header of a sortable column sorts the table in ascending or descending order.
+    pub fn set_model<M>(&self, columns: &[TableColumn], model: Rc<RefCell<M>>)
+    where
+        M: TableModel + 'static,
+    {
+        let num_columns = unsafe { tableview_column_count(self.as_ptr()) };
+        for id in (0..num_columns).rev() {
+            unsafe { tableview_del_column(self.as_ptr(), id) }
+        }
+
+        let mut sortable = Vec::new();
+        for column in columns {
+            let text = std::ffi::CString::new(column.title.as_str()).unwrap();
+            unsafe {
+                let id = tableview_add_column_text(self.as_ptr());
+                tableview_header_title(self.as_ptr(), id, text.as_ptr());
+                tableview_header_align(self.as_ptr(), id, column.align as _);
+                tableview_column_width(self.as_ptr(), id, column.width);
+                if column.sortable {
+                    sortable.push(id);
+                }
+            }
+        }
+
+        if !sortable.is_empty() {
+            unsafe { tableview_header_clickable(self.as_ptr(), 1) }
+            self.set_header_click_listener();
+        }
+        self.inner(|inner| {
+            *inner.props.sort.borrow_mut() = TableSort {
+                sortable,
+                ..Default::default()
+            }
+        });
+
+        if let Some(font) = model.borrow().font() {
+            unsafe { tableview_font(self.as_ptr(), font.as_ptr()) }
+        }
+
+        self.inner(|inner| *inner.props.model.borrow_mut() = Some(model));
+
+        let listener = listener!(self.as_ptr(), TableView, |view, event| {
+            view.inner(|inner| {
+                let Some(model) = inner.props.model.borrow().clone() else {
+                    return;
+                };
+                let Ok(model) = model.try_borrow() else {
+                    return;
+                };
+                let mut sort = inner.props.sort.borrow_mut();
+                match event.type_() {
+                    EventType::TableNRows => {
+                        let num_rows = model.num_rows();
+                        // The values can change on each update, so a sorted table is sorted again.
+                        if sort.column.is_some() || sort.rows.len() != num_rows as usize {
+                            sort.apply(&*model);
                         }
+                        unsafe { event.result(num_rows) }
+                    }
+                    EventType::TableCell => {
+                        let params = unsafe { event.params::<TablePositionEvent>() };
+                        let (column, row) = (params.x as u32, sort.model_row(params.y as u32));
+                        let cell = TableCellEvent {
+                            text: model.cell_text(row, column),
+                            align: model.cell_align(row, column),
+                            icon: model.cell_icon(row, column),
+                        };
+                        unsafe { event.result(cell) }
                     }
+                    _ => {}
                 }
+            });
+        });
+
+        unsafe { tableview_OnData(self.as_ptr(), listener) }
+    }
+
+    /// Sorts a table with a model by a column, using TableModel::compare. The header shows the
+    /// sort indicator and the selected rows are kept.
+    ///
+    /// # Remarks
+    /// The table is sorted again each time it is updated, so the rows follow the changes of the
+    /// model.
+    pub fn sort(&self, column_id: u32, ascending: bool) {
+        let Some(Some(model)) = self.inner(|inner| inner.props.model.borrow().clone()) else {
+            return;
+        };
+        let Ok(model) = model.try_borrow() else {
+            return;
+        };
+
+        let selected = self.selected().unwrap_or_default();
+        let (previous, selected) = self
+            .inner(|inner| {
+                let mut sort = inner.props.sort.borrow_mut();
+                let selected: Vec<u32> = selected.iter().map(|row| sort.model_row(*row)).collect();
+                let previous = sort.column.replace((column_id, ascending));
+                sort.apply(&*model);
+                let selected: Vec<u32> = (0..sort.rows.len() as u32)
+                    .filter(|row| selected.contains(&sort.model_row(*row)))
+                    .collect();
+                (previous, selected)
+            })
+            .unwrap_or_default();
+        drop(model);
+
+        if let Some((previous, _)) = previous {
+            if previous != column_id {
+                unsafe { tableview_header_indicator(self.as_ptr(), previous, Indicator::None as _) }
             }
-            let listener = unsafe { nappgui_sys::listener_imp(self.0 as _, Some(shim)) };
-            listener
+        }
+
+        let indicator = if ascending {
+            Indicator::UpArrow
+        } else {
+            Indicator::DownArrow
         };
 
-        unsafe { tableview_OnData(self.as_ptr(), listener) }
+        unsafe {
+            tableview_header_indicator(self.as_ptr(), column_id, indicator as _);
+            tableview_update(self.as_ptr());
+            tableview_deselect_all(self.as_ptr());
+            tableview_select(self.as_ptr(), selected.as_ptr(), selected.len() as _);
+        }
+    }
+
+    /// Gets the current sort column and whether the order is ascending.
+    pub fn sort_column(&self) -> Option<(u32, bool)> {
+        self.inner(|inner| inner.props.sort.borrow().column).flatten()
+    }
+
+    /// Gets the row of the model shown in a row of the table. They are different when the
+    /// table is sorted.
+    pub fn model_row(&self, row: u32) -> u32 {
+        self.inner(|inner| inner.props.sort.borrow().model_row(row))
+            .unwrap_or(row)
+    }
+
+    /// Sets the table in tree mode. The rows are the nodes of the tree and the column is
+    /// where the expand/collapse buttons and the indentation of the levels are drawn.
+    ///
+    /// # Remarks
+    /// The tree replaces the handler set with set_on_data_handler. Use with_tree to modify
+    /// the data, and then update to refresh the view.
+    pub fn set_tree(&self, column_id: u32, tree: TableTree) {
+        self.inner(|inner| *inner.props.tree.borrow_mut() = Some(tree));
+
+        let listener = listener!(self.as_ptr(), TableView, |view, event| {
+            let expand = view.inner(|inner| {
+                let mut tree = inner.props.tree.borrow_mut();
+                let tree = tree.as_mut()?;
+                match event.type_() {
+                    EventType::TableNRows => unsafe { event.result(tree.children(None).len() as u32) },
+                    EventType::TableNChildren => {
+                        let params = unsafe { event.params::<TableNodeEvent>() };
+                        unsafe { event.result(tree.children(params.parent).len() as u32) }
+                    }
+                    EventType::TableNodeInfo => {
+                        let params = unsafe { event.params::<TableNodeEvent>() };
+                        if let Some(&node) = tree.children(params.parent).get(params.index as usize) {
+                            let info = TableNodeInfoEvent {
+                                node,
+                                children: !tree.children(Some(node)).is_empty(),
+                                expanded: tree.is_expanded(node),
+                            };
+                            unsafe { event.result(info) }
+                        }
+                    }
+                    EventType::TableCell => {
+                        let params = unsafe { event.params::<TablePositionEvent>() };
+                        let text = params.node.and_then(|node| tree.cell(node, params.x as u32));
+                        let cell = TableCellEvent {
+                            text: text.unwrap_or_default().to_string(),
+                            align: Align::Left,
+                            icon: None,
+                        };
+                        unsafe { event.result(cell) }
+                    }
+                    EventType::TableExpand => {
+                        let params = unsafe { event.params::<TableExpandEvent>() };
+                        tree.set_expanded(params.node, params.expanded);
+                        return Some((params, inner.props.on_expand.borrow().clone()));
+                    }
+                    _ => {}
+                }
+                None
+            });
+
+            // The handler is called once the tree is released, so it can use with_tree.
+            if let Some(Some((params, Some(f)))) = expand {
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&params)));
+            }
+        });
+
+        unsafe {
+            tableview_OnData(self.as_ptr(), listener);
+            tableview_tree(self.as_ptr(), column_id);
+        }
+    }
+
+    /// Access to the tree data of a table in tree mode. Returns None if the table has no tree.
+    pub fn with_tree<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut TableTree) -> R,
+    {
+        self.inner(|inner| inner.props.tree.borrow_mut().as_mut().map(f))
+            .flatten()
+    }
+
+    /// Notify each time a node is expanded or collapsed.
+    pub fn set_on_expand_handler<F>(&self, handler: F)
+    where
+        F: Fn(&TableExpandEvent) + 'static,
+    {
+        self.inner(|inner| *inner.props.on_expand.borrow_mut() = Some(Rc::new(handler)));
+    }
+
+    /// Gets the row that occupies a node. Returns None if the node is not visible, because
+    /// some of its ancestors are collapsed.
+    pub fn node_row(&self, node: TreeNode) -> Option<u32> {
+        let row = unsafe { tableview_node_row(self.as_ptr(), node.as_ptr()) };
+        (row != u32::MAX).then_some(row)
+    }
+
+    /// Gets the node that occupies a row.
+    pub fn row_node(&self, row: u32) -> Option<TreeNode> {
+        TreeNode::from_ptr(unsafe { tableview_row_node(self.as_ptr(), row) })
     }
 
     /// Sets the general font for the entire table.
@@ -167,6 +464,11 @@ impl TableView {
         unsafe { tableview_header_align(self.as_ptr(), index, align as _) }
     }
 
+    /// Sets the indicator (sort arrow) of a column header.
+    pub fn set_header_indicator(&mut self, index: u32, indicator: Indicator) {
+        unsafe { tableview_header_indicator(self.as_ptr(), index, indicator as _) }
+    }
+
     /// Sets whether the table header is visible or not.
     pub fn set_header_visible(&mut self, visible: bool) {
         unsafe { tableview_header_visible(self.as_ptr(), visible as _) }
@@ -0,0 +1,114 @@
+use std::rc::Rc;
+
+use crate::{
+    draw_2d::Image,
+    gui::{define_object, event::ButtonEvent, listener, Callback},
+    types::GuiPosition,
+};
+
+use nappgui_sys::{
+    tabs_OnSelect, tabs_add_elem, tabs_clear, tabs_count, tabs_create, tabs_del_elem, tabs_get_image,
+    tabs_get_selected, tabs_get_text, tabs_ins_elem, tabs_length, tabs_selected, tabs_set_elem, tabs_tooltip,
+};
+
+#[derive(Default)]
+pub(crate) struct TabsProps {
+    on_select: Callback<ButtonEvent>,
+}
+
+define_object!(Tabs, TabsInner, Tabs, TabsProps);
+
+impl Tabs {
+    /// Create a tab bar. The position sets the side of the panel where the tabs are placed.
+    ///
+    /// # Remarks
+    /// The tab bar doesn't contain the pages itself. Use a Panel with one layout per tab and
+    /// switch them with Panel::set_visible_layout from the OnSelect handler.
+    pub fn new(position: GuiPosition) -> Self {
+        let tabs = unsafe { tabs_create(position as _) };
+        Self::from_raw(tabs)
+    }
+
+    /// Set an event handler for the selection of a new tab.
+    pub fn set_on_select_handler<F>(&self, callback: F)
+    where
+        F: Fn(&ButtonEvent) + 'static,
+    {
+        self.inner(|inner| *inner.props.on_select.borrow_mut() = Some(Rc::new(callback)));
+        let listener = listener!(self.as_ptr(), TabsInner, on_select(ButtonEvent));
+        unsafe { tabs_OnSelect(self.as_ptr(), listener) }
+    }
+
+    /// Set the length of the tab bar.
+    pub fn set_length(&self, length: f32) {
+        unsafe { tabs_length(self.as_ptr(), length) }
+    }
+
+    /// Assign a tooltip to the tab bar.
+    pub fn set_tooltip(&self, text: &str) {
+        let text = std::ffi::CString::new(text).unwrap();
+        unsafe { tabs_tooltip(self.as_ptr(), text.as_ptr()) }
+    }
+
+    /// Add a new tab.
+    pub fn add_element(&self, text: &str, image: Option<&Image>) {
+        let text = std::ffi::CString::new(text).unwrap();
+        let image = image.map(|image| image.as_ptr()).unwrap_or(std::ptr::null_mut());
+        unsafe { tabs_add_elem(self.as_ptr(), text.as_ptr(), image) }
+    }
+
+    /// Edit a tab.
+    pub fn set_element(&self, index: u32, text: &str, image: Option<&Image>) {
+        let text = std::ffi::CString::new(text).unwrap();
+        let image = image.map(|image| image.as_ptr()).unwrap_or(std::ptr::null_mut());
+        unsafe { tabs_set_elem(self.as_ptr(), index, text.as_ptr(), image) }
+    }
+
+    /// Inserts a tab.
+    pub fn insert_element(&self, index: u32, text: &str, image: Option<&Image>) {
+        let text = std::ffi::CString::new(text).unwrap();
+        let image = image.map(|image| image.as_ptr()).unwrap_or(std::ptr::null_mut());
+        unsafe { tabs_ins_elem(self.as_ptr(), index, text.as_ptr(), image) }
+    }
+
+    /// Remove a tab.
+    pub fn remove_element(&self, index: u32) {
+        unsafe { tabs_del_elem(self.as_ptr(), index) }
+    }
+
+    /// Remove all tabs.
+    pub fn clear(&self) {
+        unsafe { tabs_clear(self.as_ptr()) }
+    }
+
+    /// Gets the number of tabs.
+    pub fn count(&self) -> u32 {
+        unsafe { tabs_count(self.as_ptr()) }
+    }
+
+    /// Set the selected tab.
+    pub fn set_selected(&self, index: u32) {
+        unsafe { tabs_selected(self.as_ptr(), index) }
+    }
+
+    /// Get the selected tab.
+    pub fn selected(&self) -> u32 {
+        unsafe { tabs_get_selected(self.as_ptr()) }
+    }
+
+    /// Gets the text of a tab.
+    pub fn text(&self, index: u32) -> String {
+        let text = unsafe { tabs_get_text(self.as_ptr(), index) };
+        let text = unsafe { std::ffi::CStr::from_ptr(text) };
+        text.to_string_lossy().into_owned()
+    }
+
+    /// Gets the icon of a tab.
+    pub fn image(&self, index: u32) -> Option<Image> {
+        let image = unsafe { tabs_get_image(self.as_ptr(), index) };
+        if image.is_null() {
+            return None;
+        }
+        Some(unsafe { Image::from_raw_cloned(image) })
+    }
+}
@@ -0,0 +1,1640 @@
+use std::{collections::HashMap, path::Path};
+
+use roxmltree::{Attribute, Document, Node};
+
+use crate::{
+    draw_2d::{Color, Font, Image},
+    error::NappguiError,
+    gui::{
+        locale::{forget_localized_text, Localized},
+        *,
+    },
+    types::*,
+};
+
+/// Builds a user interface from XML at runtime, with the same elements and attributes as the
+/// `nappgui_ui!` macro. The layouts can be changed without recompiling.
+///
+/// # Remarks
+/// Each element declared under the root is created once, and its objects can be found by name
+/// in the returned Ui. The elements used inside other ones are created for each use instead,
+/// see Ui::object. The events of each element, like `on-click`, are accepted but ignored: the
+/// event handlers must be set on the objects found by name. The paths of the images are relative to the current
+/// directory. The texts and images of the resources, like `@HELLO_MSG`, need
+/// load_with_resources. If the XML has an error, the windows created so far are destroyed, so
+/// it can be loaded again after fixing it.
+pub fn load(xml: &str) -> Result<Ui, NappguiError> {
+    Loader::load(xml, None, &[])
+}
+
+/// Builds a user interface from XML, with texts and images of the resources. See load.
+///
+/// # Remarks
+/// The resources are pairs of name and resource ID, like the `RES_RESOURCES` generated by
+/// `include_resource!(res)`. `@HELLO_MSG` is the resource named `HELLO_MSG`, and `@@` is a
+/// literal `@`. The texts of buttons, labels, menu items and the titles of windows are set again
+/// when the language changes.
+pub fn load_with_resources(xml: &str, resources: &[(&str, &str)]) -> Result<Ui, NappguiError> {
+    Loader::load(xml, None, resources)
+}
+
+/// Builds a user interface from an XML file. See load.
+///
+/// # Remarks
+/// The paths of the images are relative to the XML file.
+pub fn load_file<P>(path: P) -> Result<Ui, NappguiError>
+where
+    P: AsRef<Path>,
+{
+    load_file_with_resources(path, &[])
+}
+
+/// Builds a user interface from an XML file, with texts and images of the resources. See
+/// load_with_resources.
+///
+/// # Remarks
+/// The paths of the images are relative to the XML file.
+pub fn load_file_with_resources<P>(path: P, resources: &[(&str, &str)]) -> Result<Ui, NappguiError>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let xml = std::fs::read_to_string(path)?;
+    Loader::load(&xml, path.parent(), resources)
+}
+
+/// An object created from the XML of a user interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiObject {
+    /// `<button>`
+    Button(Button),
+    /// `<combo>`
+    Combo(Combo),
+    /// `<edit>`
+    Edit(Edit),
+    /// `<imageview>`
+    ImageView(ImageView),
+    /// `<label>`
+    Label(Label),
+    /// `<panel>`
+    Panel(Panel),
+    /// `<listbox>`
+    ListBox(ListBox),
+    /// `<popup>`
+    PopUp(PopUp),
+    /// `<progress>`
+    Progress(Progress),
+    /// `<slider>`
+    Slider(Slider),
+    /// `<splitview>`
+    SplitView(SplitView),
+    /// `<tableview>`
+    TableView(TableView),
+    /// `<tabs>`
+    Tabs(Tabs),
+    /// `<textview>`
+    TextView(TextView),
+    /// `<updown>`
+    UpDown(UpDown),
+    /// `<view>`
+    View(View),
+    /// `<webview>`
+    WebView(WebView),
+    /// `<line>`
+    Line(Line),
+    /// `<layout>`
+    Layout(Layout),
+    /// `<window>`
+    Window(Window),
+    /// `<menu>`
+    Menu(Menu),
+    /// `<menuitem>`
+    MenuItem(MenuItem),
+}
+
+impl UiObject {
+    /// Converts the object to a Control, which can be converted to the type of control with
+    /// `as_button`, `as_label`, etc. Returns None for layouts, windows and menus.
+    pub fn control(self) -> Option<Control> {
+        let control = match self {
+            UiObject::Button(button) => button.as_object(),
+            UiObject::Combo(combo) => combo.as_object(),
+            UiObject::Edit(edit) => edit.as_object(),
+            UiObject::ImageView(view) => view.as_object(),
+            UiObject::Label(label) => label.as_object(),
+            UiObject::Panel(panel) => panel.as_object(),
+            UiObject::ListBox(listbox) => listbox.as_object(),
+            UiObject::PopUp(popup) => popup.as_object(),
+            UiObject::Progress(progress) => progress.as_object(),
+            UiObject::Slider(slider) => slider.as_object(),
+            UiObject::SplitView(split) => split.as_object(),
+            UiObject::TableView(table) => table.as_object(),
+            UiObject::Tabs(tabs) => tabs.as_object(),
+            UiObject::TextView(text) => text.as_object(),
+            UiObject::UpDown(updown) => updown.as_object(),
+            UiObject::View(view) => view.as_object(),
+            UiObject::WebView(view) => view.as_object(),
+            UiObject::Line(line) => line.as_object(),
+            UiObject::Layout(_) | UiObject::Window(_) | UiObject::Menu(_) | UiObject::MenuItem(_) => return None,
+        };
+        Some(control)
+    }
+
+    /// The GUID of the object in the registry of objects.
+    fn guid(self) -> GUID {
+        match self {
+            UiObject::Layout(layout) => layout.0,
+            UiObject::Window(window) => window.0,
+            UiObject::Menu(menu) => menu.0,
+            UiObject::MenuItem(item) => item.0,
+            // The controls keep their GUID as pointer, see AsObject.
+            object => object.control().expect("the other objects are controls").as_ptr() as GUID,
+        }
+    }
+}
+
+/// A user interface loaded from XML. The objects are found by their `name` attribute, and the
+/// elements declared under the root by their tag, like `MainWindow`.
+///
+/// # Remarks
+/// If several elements declare the same name, the name can be qualified with the element, like
+/// `MainWindow.ok`. Otherwise, the first element that declares it is used. The objects of an
+/// element used inside another one are found through the name of the use (or its tag, if it
+/// has no name), like `MainWindow.mypanel.ok` for `<MyPanel name="mypanel"/>`.
+pub struct Ui {
+    elements: Vec<UiElement>,
+}
+
+struct UiElement {
+    name: String,
+    object: UiObject,
+    objects: HashMap<String, UiObject>,
+}
+
+impl Ui {
+    /// Gets an object by its name, or by its path for the elements used inside other ones.
+    pub fn object(&self, name: &str) -> Option<UiObject> {
+        if let Some((element, name)) = name.split_once('.') {
+            let objects = &self.elements.iter().find(|e| e.name == element)?.objects;
+            return objects.get(name).copied();
+        }
+        self.elements
+            .iter()
+            .find(|element| element.name == name)
+            .map(|element| element.object)
+            .or_else(|| {
+                self.elements
+                    .iter()
+                    .find_map(|element| element.objects.get(name).copied())
+            })
+    }
+
+    /// Gets a control by its name. It can be converted to its type with `as_button`, `as_label`,
+    /// etc.
+    pub fn control(&self, name: &str) -> Option<Control> {
+        self.object(name)?.control()
+    }
+
+    /// Gets a layout by its name.
+    pub fn layout(&self, name: &str) -> Option<Layout> {
+        match self.object(name)? {
+            UiObject::Layout(layout) => Some(layout),
+            _ => None,
+        }
+    }
+
+    /// Gets a window by its name.
+    pub fn window(&self, name: &str) -> Option<Window> {
+        match self.object(name)? {
+            UiObject::Window(window) => Some(window),
+            _ => None,
+        }
+    }
+
+    /// Gets a menu by its name.
+    pub fn menu(&self, name: &str) -> Option<Menu> {
+        match self.object(name)? {
+            UiObject::Menu(menu) => Some(menu),
+            _ => None,
+        }
+    }
+
+    /// Gets a menu item by its name.
+    pub fn menu_item(&self, name: &str) -> Option<MenuItem> {
+        match self.object(name)? {
+            UiObject::MenuItem(item) => Some(item),
+            _ => None,
+        }
+    }
+
+    /// The tags of the elements declared under the root and not used inside other ones, in
+    /// document order.
+    pub fn elements(&self) -> impl Iterator<Item = &str> {
+        self.elements.iter().map(|element| element.name.as_str())
+    }
+}
+
+/// The types of elements of the XML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Button,
+    Combo,
+    Edit,
+    ImageView,
+    Label,
+    Panel,
+    ListBox,
+    PopUp,
+    Progress,
+    Slider,
+    SplitView,
+    TableView,
+    Tabs,
+    TextView,
+    UpDown,
+    View,
+    WebView,
+    Line,
+    Layout,
+    Window,
+    Menu,
+    MenuItem,
+    Cell,
+    Item,
+    Column,
+    Row,
+}
+
+impl Kind {
+    /// The type of a tag. Returns None for user defined elements.
+    fn from_tag(tag: &str) -> Option<Self> {
+        let kind = match tag.trim().to_lowercase().as_str() {
+            "button" => Self::Button,
+            "combo" => Self::Combo,
+            "edit" => Self::Edit,
+            "imageview" | "image-view" | "image_view" => Self::ImageView,
+            "label" => Self::Label,
+            "panel" => Self::Panel,
+            "listbox" | "list-box" | "list_box" => Self::ListBox,
+            "popup" => Self::PopUp,
+            "progress" => Self::Progress,
+            "slider" => Self::Slider,
+            "splitview" | "split-view" | "split_view" => Self::SplitView,
+            "tableview" | "table-view" | "table_view" => Self::TableView,
+            "tabs" => Self::Tabs,
+            "textview" | "text-view" | "text_view" => Self::TextView,
+            "updown" | "up-down" | "up_down" => Self::UpDown,
+            "view" => Self::View,
+            "webview" | "web-view" | "web_view" => Self::WebView,
+            "line" => Self::Line,
+            "layout" => Self::Layout,
+            "window" | "win" => Self::Window,
+            "menu" => Self::Menu,
+            "menuitem" | "menu-item" | "menu_item" => Self::MenuItem,
+            "cell" => Self::Cell,
+            "item" => Self::Item,
+            "column" | "col" => Self::Column,
+            "row" => Self::Row,
+            _ => return None,
+        };
+        Some(kind)
+    }
+
+    /// The elements that describe a part of their parent, instead of creating an object.
+    fn is_part(self) -> bool {
+        matches!(self, Self::Cell | Self::Item | Self::Column | Self::Row)
+    }
+
+    /// The attributes used to create or place the object, which are not setters.
+    fn is_option(self, name: &str) -> bool {
+        match self {
+            Self::Button => name == "type",
+            Self::Edit => matches!(name, "multiline" | "multi-line"),
+            Self::Panel => matches!(
+                name,
+                "horizontal-scroll"
+                    | "horizontal_scroll"
+                    | "hscroll"
+                    | "vertical-scroll"
+                    | "vertical_scroll"
+                    | "vscroll"
+                    | "border"
+                    | "has-border"
+            ),
+            Self::Slider | Self::Line => name == "vertical",
+            Self::SplitView => matches!(name, "vertical" | "position" | "pos" | "split-mode" | "mode"),
+            Self::Tabs => matches!(name, "position" | "pos"),
+            Self::TextView => name == "write",
+            Self::Layout => matches!(name, "cols" | "columns" | "rows" | "group"),
+            Self::Window => name == "default-button",
+            Self::MenuItem => name == "separator",
+            _ => false,
+        }
+    }
+
+    /// The event handlers that can be declared for the type, like in `nappgui_ui!`.
+    fn is_event(self, name: &str) -> bool {
+        let events: &[&str] = match self {
+            Self::Button | Self::UpDown => &["on-click"],
+            Self::Combo => &["on-filter", "on-change", "on-focus", "on-select"],
+            Self::Edit => &["on-filter", "on-change", "on-focus"],
+            Self::ImageView => &["on-click", "on-over-draw"],
+            Self::Label | Self::MenuItem => &["on-click"],
+            Self::Layout => &["on-dbind-change", "on-change"],
+            Self::ListBox => &["on-down", "on-select"],
+            Self::PopUp | Self::Tabs => &["on-select"],
+            Self::Slider => &["on-moved"],
+            Self::TableView => &["on-select", "on-row-click", "on-header-click", "on-data", "on-expand"],
+            Self::TextView => &["on-filter", "on-focus"],
+            Self::View => &[
+                "on-draw",
+                "on-overlay",
+                "on-size",
+                "on-enter",
+                "on-exit",
+                "on-move",
+                "on-down",
+                "on-up",
+                "on-click",
+                "on-drag",
+                "on-wheel",
+                "on-key-down",
+                "on-key-up",
+                "on-focus",
+                "on-accept-focus",
+                "on-resign-focus",
+                "on-scroll",
+            ],
+            Self::WebView => &["on-focus"],
+            Self::Window => &["on-close", "on-moved", "on-resize"],
+            _ => &[],
+        };
+        events.contains(&name)
+    }
+
+    /// The controls with a `set_font` method, set from the `font-*` attributes.
+    fn has_font(self) -> bool {
+        matches!(
+            self,
+            Self::Button | Self::Label | Self::Edit | Self::ListBox | Self::TableView
+        )
+    }
+}
+
+/// An element of the XML and the object created from it.
+struct Built<'a, 'input> {
+    node: Node<'a, 'input>,
+    kind: Kind,
+    object: Option<UiObject>,
+    /// Index of the parent in the list of elements. The first element has no parent.
+    parent: Option<usize>,
+    /// Objects of a user defined element, by their path in the element.
+    objects: HashMap<String, UiObject>,
+}
+
+struct Loader<'a, 'input> {
+    doc: &'a Document<'input>,
+    /// Directory of the XML file, used to find the images.
+    dir: Option<&'a Path>,
+    /// Elements declared under the root, which can be used as elements in other ones.
+    definitions: HashMap<&'a str, Node<'a, 'input>>,
+    /// Elements being built, to detect the elements declared in themselves.
+    building: Vec<&'a str>,
+    /// Elements that have been built at least once.
+    built: Vec<&'a str>,
+    /// Name and resource ID of the texts and images of the resources.
+    resources: &'a [(&'a str, &'a str)],
+    /// Objects created so far, destroyed if the XML has an error.
+    created: Vec<UiObject>,
+}
+
+impl<'a, 'input> Loader<'a, 'input> {
+    fn load(xml: &str, dir: Option<&Path>, resources: &[(&str, &str)]) -> Result<Ui, NappguiError> {
+        let doc = Document::parse(xml).map_err(|err| NappguiError::Xml {
+            message: err.to_string(),
+            row: err.pos().row,
+            col: err.pos().col,
+        })?;
+        let root = doc.root_element();
+        let mut loader = Loader {
+            doc: &doc,
+            dir,
+            definitions: HashMap::new(),
+            building: Vec::new(),
+            built: Vec::new(),
+            resources,
+            created: Vec::new(),
+        };
+        if let Some(attr) = root
+            .attributes()
+            .find(|attr| !matches!(attr.name(), "mod" | "module" | "namespacing"))
+        {
+            return Err(loader.unknown_attr(root, &attr));
+        }
+        for node in root.children().filter(Node::is_element) {
+            let tag = node.tag_name().name();
+            if loader.definitions.insert(tag, node).is_some() {
+                return Err(loader.error(tag_pos(node), format!("`<{}>` is declared more than once", tag)));
+            }
+        }
+
+        match loader.build_elements(root) {
+            Ok(elements) => Ok(Ui { elements }),
+            Err(err) => {
+                loader.destroy_created();
+                Err(err)
+            }
+        }
+    }
+
+    /// Builds the elements declared under the root, except the ones used inside other ones.
+    fn build_elements(&mut self, root: Node<'a, 'input>) -> Result<Vec<UiElement>, NappguiError> {
+        // The elements used inside other ones are created for each use.
+        let used: Vec<&str> = root
+            .children()
+            .filter(Node::is_element)
+            .flat_map(|node| node.descendants().skip(1))
+            .map(|node| node.tag_name().name())
+            .filter(|tag| self.definitions.contains_key(tag))
+            .collect();
+
+        let mut elements = Vec::new();
+        for node in root.children().filter(Node::is_element) {
+            if used.contains(&node.tag_name().name()) {
+                continue;
+            }
+            let (_, object, objects) = self.build(node)?;
+            elements.push(UiElement {
+                name: node.tag_name().name().to_string(),
+                object,
+                objects,
+            });
+        }
+        // The elements that are only used inside themselves, directly or through other ones,
+        // are built to report the error.
+        for node in root.children().filter(Node::is_element) {
+            if !self.built.contains(&node.tag_name().name()) {
+                self.build(node)?;
+            }
+        }
+        Ok(elements)
+    }
+
+    /// Destroys the windows and menus created before an error, with their controls, so a failed
+    /// load doesn't leave them behind. The objects are removed from the registry of objects.
+    fn destroy_created(&mut self) {
+        for object in self.created.drain(..).rev() {
+            match object {
+                UiObject::Button(button) => forget_localized_text(Localized::Button(button)),
+                UiObject::Label(label) => forget_localized_text(Localized::Label(label)),
+                UiObject::MenuItem(item) => forget_localized_text(Localized::MenuItem(item)),
+                UiObject::Window(window) => {
+                    forget_localized_text(Localized::WindowTitle(window));
+                    window.destroy();
+                }
+                UiObject::Menu(menu) => menu.destroy(),
+                _ => (),
+            }
+            global_object_remove(object.guid());
+        }
+    }
+
+    /// Builds an element declared under the root. Returns its type, its object and the objects
+    /// of its children by name.
+    fn build(&mut self, def: Node<'a, 'input>) -> Result<(Kind, UiObject, HashMap<String, UiObject>), NappguiError> {
+        let tag = def.tag_name().name();
+        if self.building.contains(&tag) {
+            return Err(self.error(tag_pos(def), format!("`<{}>` is declared in itself", tag)));
+        }
+        let Some(inherits) = attr(def, &["inherits", "extends"]) else {
+            return Err(self.error(
+                tag_pos(def),
+                format!("`<{}>` must inherit from a type, like `inherits=\"Window\"`", tag),
+            ));
+        };
+        let kind = match Kind::from_tag(inherits.value()) {
+            Some(kind) if !kind.is_part() => kind,
+            _ => return Err(self.invalid_value(&inherits, "the type of an object, like `Window` or `Panel`")),
+        };
+
+        self.building.push(tag);
+        self.built.push(tag);
+        let object = self.create(def, kind)?;
+        self.created.extend(object);
+        let mut nodes = vec![Built {
+            node: def,
+            kind,
+            object,
+            parent: None,
+            objects: HashMap::new(),
+        }];
+        let created = self.create_children(0, &mut nodes);
+        self.building.pop();
+        created?;
+
+        let mut names = HashMap::new();
+        for built in &nodes[1..] {
+            let (Some(name), Some(object)) = (attr(built.node, &["name"]), built.object) else {
+                continue;
+            };
+            if names.insert(name.value(), object).is_some() {
+                return Err(self.error(
+                    name.range_value().start,
+                    format!("the name `{}` is declared more than once", name.value()),
+                ));
+            }
+        }
+
+        for built in &nodes {
+            self.place(built, &nodes, &names)?;
+        }
+        // The root is configured after its children, like in the generated code of `nappgui_ui!`.
+        for built in nodes[1..].iter().chain(&nodes[..1]) {
+            self.apply(built, &nodes, &names)?;
+        }
+
+        let mut objects: HashMap<String, UiObject> = names
+            .into_iter()
+            .map(|(name, object)| (name.to_string(), object))
+            .collect();
+        for built in &nodes[1..] {
+            let prefix = attr(built.node, &["name"]).map_or(built.node.tag_name().name(), |name| name.value());
+            for (path, object) in &built.objects {
+                objects.entry(format!("{}.{}", prefix, path)).or_insert(*object);
+            }
+        }
+        Ok((kind, nodes[0].object.unwrap(), objects))
+    }
+
+    /// Creates the objects of the children of an element, in document order.
+    fn create_children(&mut self, parent: usize, nodes: &mut Vec<Built<'a, 'input>>) -> Result<(), NappguiError> {
+        let (parent_node, parent_kind) = (nodes[parent].node, nodes[parent].kind);
+        for node in parent_node.children().filter(Node::is_element) {
+            let tag = node.tag_name().name();
+            if parent_kind.is_part() {
+                return Err(self.error(
+                    tag_pos(node),
+                    format!("`<{}>` can't have child elements", parent_node.tag_name().name()),
+                ));
+            }
+            let (kind, object, objects, custom) = match Kind::from_tag(tag) {
+                Some(kind) => (kind, None, HashMap::new(), false),
+                None => match self.definitions.get(tag).copied() {
+                    Some(def) => {
+                        let (kind, object, objects) = self.build(def)?;
+                        (kind, Some(object), objects, true)
+                    }
+                    None => return Err(self.error(tag_pos(node), format!("unknown element `<{}>`", tag))),
+                },
+            };
+            if !placement_valid(kind, custom, parent_kind) {
+                return Err(self.error(
+                    tag_pos(node),
+                    format!("`<{}>` can't be declared in `<{}>`", tag, parent_node.tag_name().name()),
+                ));
+            }
+            let object = match object {
+                Some(object) => Some(object),
+                None => {
+                    let object = self.create(node, kind)?;
+                    self.created.extend(object);
+                    object
+                }
+            };
+            nodes.push(Built {
+                node,
+                kind,
+                object,
+                parent: Some(parent),
+                objects,
+            });
+            // The children of a user defined element are created by its own definition.
+            if custom {
+                if let Some(child) = node.children().find(Node::is_element) {
+                    return Err(self.error(tag_pos(child), format!("`<{}>` can't have child elements", tag)));
+                }
+            } else {
+                self.create_children(nodes.len() - 1, nodes)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates the object of an element, with the options of its constructor.
+    fn create(&self, node: Node, kind: Kind) -> Result<Option<UiObject>, NappguiError> {
+        let object = match kind {
+            Kind::Button => {
+                let types = &["push", "check", "check3", "radio", "flat", "flatgle"];
+                let button = match self.choice(node, &["type"], types)? {
+                    Some("check") => Button::new_check(),
+                    Some("check3") => Button::new_check3(),
+                    Some("radio") => Button::new_radio(),
+                    Some("flat") => Button::new_flat(),
+                    Some("flatgle") => Button::new_flatgle(),
+                    _ => Button::new(),
+                };
+                UiObject::Button(button)
+            }
+            Kind::Combo => UiObject::Combo(Combo::new()),
+            Kind::Edit => {
+                if self.flag(node, &["multiline", "multi-line"])? {
+                    UiObject::Edit(Edit::new_multiline())
+                } else {
+                    UiObject::Edit(Edit::new())
+                }
+            }
+            Kind::ImageView => UiObject::ImageView(ImageView::new()),
+            Kind::Label => UiObject::Label(Label::new()),
+            Kind::Panel => {
+                let horizontal_scroll = self.flag(node, &["horizontal-scroll", "horizontal_scroll", "hscroll"])?;
+                let vertical_scroll = self.flag(node, &["vertical-scroll", "vertical_scroll", "vscroll"])?;
+                let border = self.flag(node, &["border", "has-border"])?;
+                let panel = match (horizontal_scroll, vertical_scroll, border) {
+                    (false, false, false) => Panel::new(),
+                    (_, _, false) => Panel::new_scroll(horizontal_scroll, vertical_scroll),
+                    (_, _, true) => Panel::new_custom(horizontal_scroll, vertical_scroll, border),
+                };
+                UiObject::Panel(panel)
+            }
+            Kind::ListBox => UiObject::ListBox(ListBox::new()),
+            Kind::PopUp => UiObject::PopUp(PopUp::new()),
+            Kind::Progress => UiObject::Progress(Progress::new()),
+            Kind::Slider => {
+                if self.flag(node, &["vertical"])? {
+                    UiObject::Slider(Slider::new_vertical())
+                } else {
+                    UiObject::Slider(Slider::new())
+                }
+            }
+            Kind::SplitView => {
+                if self.flag(node, &["vertical"])? {
+                    UiObject::SplitView(SplitView::new_vertical())
+                } else {
+                    UiObject::SplitView(SplitView::new())
+                }
+            }
+            Kind::TableView => UiObject::TableView(TableView::new()),
+            Kind::Tabs => {
+                let position = match self.choice(node, &["position", "pos"], &["top", "bottom", "left", "right"])? {
+                    Some("bottom") => GuiPosition::Bottom,
+                    Some("left") => GuiPosition::Left,
+                    Some("right") => GuiPosition::Right,
+                    _ => GuiPosition::Top,
+                };
+                UiObject::Tabs(Tabs::new(position))
+            }
+            Kind::TextView => UiObject::TextView(TextView::new()),
+            Kind::UpDown => UiObject::UpDown(UpDown::new()),
+            Kind::View => UiObject::View(View::new()),
+            Kind::WebView => UiObject::WebView(WebView::new()),
+            Kind::Line => {
+                if self.flag(node, &["vertical"])? {
+                    UiObject::Line(Line::new_vertical())
+                } else {
+                    UiObject::Line(Line::new())
+                }
+            }
+            Kind::Layout => {
+                let columns = match attr(node, &["cols", "columns"]) {
+                    Some(attr) => self.uint(&attr)?,
+                    None => 1,
+                };
+                let rows = match attr(node, &["rows"]) {
+                    Some(attr) => self.uint(&attr)?,
+                    None => 1,
+                };
+                UiObject::Layout(Layout::new(columns, rows))
+            }
+            Kind::Window => UiObject::Window(Window::new()),
+            Kind::Menu => UiObject::Menu(Menu::new()),
+            Kind::MenuItem => {
+                if self.flag(node, &["separator"])? {
+                    UiObject::MenuItem(MenuItem::new_separator())
+                } else {
+                    UiObject::MenuItem(MenuItem::new())
+                }
+            }
+            Kind::Cell | Kind::Item | Kind::Column | Kind::Row => return Ok(None),
+        };
+        Ok(Some(object))
+    }
+
+    /// Places the layouts in their panels and the controls in the cells of their layouts.
+    fn place(&self, built: &Built, nodes: &[Built], names: &HashMap<&str, UiObject>) -> Result<(), NappguiError> {
+        let Some(parent) = built.parent.map(|parent| &nodes[parent]) else {
+            return Ok(());
+        };
+        match (built.kind, built.object, parent.object) {
+            // A layout declared in another layout is a sublayout, placed with a `<cell>`.
+            (Kind::Layout, Some(UiObject::Layout(layout)), Some(UiObject::Panel(panel))) => {
+                panel.add_layout(layout);
+            }
+            (Kind::Cell, _, Some(UiObject::Layout(layout))) => {
+                let control = self.required(built.node, &["for", "control"])?;
+                let (col, row) = self.cell_index(built.node, layout)?;
+                let Some(object) = names.get(control.value()) else {
+                    return Err(self.error(
+                        control.range_value().start,
+                        format!("`{}` is not declared", control.value()),
+                    ));
+                };
+                if !set_control(layout, col, row, *object) {
+                    return Err(self.error(
+                        control.range_value().start,
+                        format!("`{}` can't be placed in a layout", control.value()),
+                    ));
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Applies the attributes and the parts of an element.
+    fn apply(&self, built: &Built, nodes: &[Built], names: &HashMap<&str, UiObject>) -> Result<(), NappguiError> {
+        let parent = built.parent.map(|parent| &nodes[parent]);
+        let object = match (built.kind, built.object) {
+            (Kind::Cell, _) => match parent.and_then(|parent| parent.object) {
+                Some(UiObject::Layout(layout)) => return self.apply_cell(built.node, layout),
+                _ => return Ok(()),
+            },
+            (_, Some(object)) => object,
+            // Items and columns are applied by their parent.
+            _ => return Ok(()),
+        };
+
+        // A user defined element is configured by its definition, only its name can be declared.
+        let custom = Kind::from_tag(built.node.tag_name().name()).is_none() && built.parent.is_some();
+        match object {
+            _ if custom => (),
+            UiObject::ListBox(_) | UiObject::PopUp(_) | UiObject::Combo(_) | UiObject::Tabs(_) => {
+                self.apply_items(built.node, object)?
+            }
+            UiObject::TableView(table) => self.apply_table_columns(built.node, table)?,
+            UiObject::Layout(layout) => self.apply_layout_lines(built.node, layout)?,
+            UiObject::Menu(menu) => {
+                for child in nodes
+                    .iter()
+                    .filter(|child| child.parent.is_some_and(|p| nodes[p].node == built.node))
+                {
+                    if let Some(UiObject::MenuItem(item)) = child.object {
+                        menu.add_item(item);
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        for attr in built.node.attributes() {
+            let name = attr.name();
+            let known = match name {
+                "name" => true,
+                "inherits" | "extends" => built.parent.is_none(),
+                _ if custom => false,
+                "font-family" | "font-size" | "font-style" if built.kind.has_font() => true,
+                _ if built.kind.is_event(name) || built.kind.is_option(name) => true,
+                _ => self.set(object, &attr)?,
+            };
+            if !known {
+                return Err(self.unknown_attr(built.node, &attr));
+            }
+        }
+        if custom {
+            return Ok(());
+        }
+
+        if built.kind.has_font() {
+            if let Some(font) = self.font(built.node)? {
+                match object {
+                    UiObject::Button(button) => button.set_font(&font),
+                    UiObject::Label(label) => label.set_font(&font),
+                    UiObject::Edit(edit) => edit.set_font(&font),
+                    UiObject::ListBox(listbox) => listbox.set_font(&font),
+                    UiObject::TableView(mut table) => table.set_font(&font),
+                    _ => (),
+                }
+            }
+        }
+        let parent_object = parent.and_then(|parent| parent.object);
+        match (object, parent_object) {
+            (UiObject::Layout(layout), _) => {
+                if let Some(group) = attr(built.node, &["group"]) {
+                    layout.set_group(true, &self.text(&group)?);
+                }
+            }
+            (UiObject::SplitView(split), _) => {
+                if let Some(position) = attr(built.node, &["position", "pos"]) {
+                    let position = self.float(&position)?;
+                    let mode =
+                        match self.choice(built.node, &["split-mode", "mode"], &["normal", "fixed0", "fixed1"])? {
+                            Some("fixed0") => SplitMode::Fixed0,
+                            Some("fixed1") => SplitMode::Fixed1,
+                            _ => SplitMode::Normal,
+                        };
+                    split.set_position(mode, position);
+                }
+            }
+            (UiObject::Window(window), _) => {
+                if let Some(button) = attr(built.node, &["default-button"]) {
+                    match names.get(button.value()) {
+                        Some(UiObject::Button(default_button)) => window.set_default_button(*default_button),
+                        _ => {
+                            return Err(self.error(
+                                button.range_value().start,
+                                format!("`{}` is not a button", button.value()),
+                            ))
+                        }
+                    }
+                }
+            }
+            // The menu is the menu bar of a window, or the submenu of an item.
+            (UiObject::Menu(menu), Some(UiObject::Window(window))) => window.set_menubar(menu),
+            (UiObject::Menu(menu), Some(UiObject::MenuItem(item))) => item.set_submenu(menu),
+            // The panel is the main panel of the window it is declared in.
+            (UiObject::Panel(panel), Some(UiObject::Window(window))) => {
+                if panel.layout(0).is_none() {
+                    return Err(self.error(tag_pos(built.node), "the panel of a window needs a layout".to_string()));
+                }
+                window.set_panel(panel);
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Calls the setter of an attribute. Returns false if the object has no setter for it.
+    fn set(&self, object: UiObject, attr: &Attribute) -> Result<bool, NappguiError> {
+        let text = attr.value();
+        match object {
+            UiObject::Button(button) => match attr.name() {
+                "text" => self.localized(attr, |text| button.set_text(text), |rid| button.set_text_rid(rid))?,
+                "text-alt" | "set-text-alt" => button.set_text_alt(&self.text(attr)?),
+                "width" => button.set_width(self.float(attr)?),
+                "image" => button.set_image(&self.image(attr)?),
+                "image-alt" => button.set_image_alt(&self.image(attr)?),
+                "image-position" => button.set_image_position(self.position(attr)?),
+                "state" => button.set_state(self.state(attr)?),
+                "tooltip" => button.set_tooltip(&self.text(attr)?),
+                "hpadding" => button.set_hpadding(self.float(attr)?),
+                "vpadding" => button.set_vpadding(self.float(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::Combo(combo) => match attr.name() {
+                "width" => combo.set_width(self.float(attr)?),
+                "text" => combo.set_text(&self.text(attr)?),
+                "align" => combo.set_align(self.align(attr)?),
+                "passmode" => combo.set_passmode(self.boolean(attr)?),
+                "editable" => combo.set_editable(self.boolean(attr)?),
+                "autoselect" => combo.set_autoselect(self.boolean(attr)?),
+                "tooltip" => combo.set_tooltip(&self.text(attr)?),
+                "color" => combo.set_color(Color::html(text.trim())),
+                "color-focus" => combo.set_color_focus(Color::html(text.trim())),
+                "background-color" => combo.set_background_color(Color::html(text.trim())),
+                "background-color-focus" => combo.set_background_color_focus(Color::html(text.trim())),
+                "placeholder" | "placeholder-text" => combo.set_placeholder_text(&self.text(attr)?),
+                "placeholder-color" => combo.set_placeholder_color(Color::html(text.trim())),
+                "placeholder-style" => combo.set_placeholder_style(self.font_style(attr)?),
+                "list-height" => combo.set_list_height(self.uint(attr)?),
+                "selected" => combo.set_selected(self.uint(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::Edit(edit) => match attr.name() {
+                "text" => edit.set_text(&self.text(attr)?),
+                "align" => edit.set_align(self.align(attr)?),
+                "passmode" => edit.set_passmode(self.boolean(attr)?),
+                "editable" => edit.set_editable(self.boolean(attr)?),
+                "autoselect" => edit.set_autoselect(self.boolean(attr)?),
+                "select" => {
+                    let [start, end] = self.pair(attr, "two integers, like `0,5`")?;
+                    edit.set_select(start, end);
+                }
+                "tooltip" => edit.set_tooltip(&self.text(attr)?),
+                "color" => edit.set_color(Color::html(text.trim())),
+                "color-focus" => edit.set_color_focus(Color::html(text.trim())),
+                "background-color" => edit.set_background_color(Color::html(text.trim())),
+                "background-color-focus" => edit.set_background_color_focus(Color::html(text.trim())),
+                "placeholder" | "placeholder-text" => edit.set_placeholder_text(&self.text(attr)?),
+                "placeholder-color" => edit.set_placeholder_color(Color::html(text.trim())),
+                "placeholder-style" => edit.set_placeholder_style(self.font_style(attr)?),
+                "vpadding" => edit.set_vpadding(self.float(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::ImageView(view) => match attr.name() {
+                "size" => {
+                    let [width, height] = self.size(attr)?;
+                    view.set_size(width, height);
+                }
+                "scale" => view.set_scale(self.scale(attr)?),
+                "image" => view.set_image(&self.image(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::Label(label) => match attr.name() {
+                "text" => self.localized(attr, |text| label.set_text(text), |rid| label.set_text_rid(rid))?,
+                "width" => label.set_width(self.float(attr)?),
+                "multiline" | "multi-line" => label.set_multiline(self.boolean(attr)?),
+                "align" => label.set_align(self.align(attr)?),
+                "color" => label.set_color(Color::html(text.trim())),
+                "color-over" => label.set_color_over(Color::html(text.trim())),
+                "background-color" => label.set_background_color(Color::html(text.trim())),
+                "background-color-over" => label.set_background_color_over(Color::html(text.trim())),
+                "style-over" => label.set_style_over(self.font_style(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::Panel(panel) => match attr.name() {
+                "size" => {
+                    let [width, height] = self.size(attr)?;
+                    panel.set_size(width, height);
+                }
+                "visible-layout" => panel.set_visible_layout(self.uint(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::ListBox(listbox) => match attr.name() {
+                "size" => {
+                    let [width, height] = self.size(attr)?;
+                    listbox.size(width, height);
+                }
+                "checkbox" => listbox.set_checkbox(self.boolean(attr)?),
+                "multiselect" => listbox.set_multiselect(self.boolean(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::PopUp(popup) => match attr.name() {
+                "tooltip" => popup.set_tooltip(&self.text(attr)?),
+                "list-height" => popup.set_list_height(self.uint(attr)?),
+                "selected" => popup.set_selected(self.uint(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::Progress(progress) => match attr.name() {
+                "width" => progress.set_width(self.float(attr)?),
+                "undefined" => progress.set_undefined(self.boolean(attr)?),
+                "value" => progress.set_value(self.float(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::Slider(slider) => match attr.name() {
+                "length" => slider.set_length(self.float(attr)?),
+                "tooltip" => slider.set_tooltip(&self.text(attr)?),
+                "steps" => slider.set_steps(self.uint(attr)?),
+                "value" => slider.set_value(self.float(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::SplitView(split) => match attr.name() {
+                "first-visible" => split.set_first_visible(self.boolean(attr)?),
+                "last-visible" => split.set_last_visible(self.boolean(attr)?),
+                "first-min-size" => split.set_first_min_size(self.float(attr)?),
+                "last-min-size" => split.set_last_min_size(self.float(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::TableView(mut table) => match attr.name() {
+                "size" => {
+                    let [width, height] = self.size(attr)?;
+                    table.set_size(width, height);
+                }
+                "column-freeze" => table.set_column_freeze(self.uint(attr)?),
+                "header-visible" => table.set_header_visible(self.boolean(attr)?),
+                "header-clickable" => table.set_header_clickable(self.boolean(attr)?),
+                "header-resizable" => table.set_header_resizable(self.boolean(attr)?),
+                "header-height" => table.set_header_height(self.float(attr)?),
+                "row-height" => table.set_row_height(self.float(attr)?),
+                "multiselect" => {
+                    let [multiselect, preserve] = self.bools(attr)?;
+                    table.set_multiselect(multiselect, preserve);
+                }
+                "grid" => {
+                    let [hlines, vlines] = self.bools(attr)?;
+                    table.set_grid(hlines, vlines);
+                }
+                "scroll-visible" => {
+                    let [horizontal, vertical] = self.bools(attr)?;
+                    table.set_scroll_visible(horizontal, vertical);
+                }
+                _ => return Ok(false),
+            },
+            UiObject::Tabs(tabs) => match attr.name() {
+                "length" => tabs.set_length(self.float(attr)?),
+                "tooltip" => tabs.set_tooltip(&self.text(attr)?),
+                "selected" => tabs.set_selected(self.uint(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::TextView(view) => match attr.name() {
+                "size" => {
+                    let [width, height] = self.size(attr)?;
+                    view.set_size(width, height);
+                }
+                "units" => view.set_units(self.uint(attr)?),
+                "font-family" => view.set_font_family(&self.text(attr)?),
+                "font-size" => view.set_font_size(self.float(attr)?),
+                "font-style" => view.set_font_style(self.font_style(attr)?),
+                "color" => view.set_color(Color::html(text.trim())),
+                "background-color" => view.set_background_color(Color::html(text.trim())),
+                "align" | "horizontal-align" => view.set_horizontal_align(self.align(attr)?),
+                "line-spacing" => view.set_line_spacing(self.float(attr)?),
+                "before-space" => view.set_before_space(self.float(attr)?),
+                "after-space" => view.set_after_space(self.float(attr)?),
+                "scroll-visible" => {
+                    let [horizontal, vertical] = self.bools(attr)?;
+                    view.set_scroll_visible(horizontal, vertical);
+                }
+                "editable" => view.set_editable(self.boolean(attr)?),
+                "select" => {
+                    let [start, end] = self.pair(attr, "two integers, like `0,5`")?;
+                    view.set_select(start, end);
+                }
+                "show-select" => view.set_show_select(self.boolean(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::UpDown(updown) => match attr.name() {
+                "tooltip" => updown.set_tooltip(&self.text(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::View(view) => match attr.name() {
+                "size" => {
+                    let [width, height] = self.size(attr)?;
+                    view.set_size(width, height);
+                }
+                "tooltip" => view.set_tooltip(&self.text(attr)?),
+                "allow-tab" => view.set_allow_tab(self.boolean(attr)?),
+                "scroll-visible" => {
+                    let [horizontal, vertical] = self.bools(attr)?;
+                    view.set_scroll_visible(horizontal, vertical);
+                }
+                _ => return Ok(false),
+            },
+            UiObject::WebView(view) => match attr.name() {
+                "size" => {
+                    let [width, height] = self.size(attr)?;
+                    view.set_size(width, height);
+                }
+                _ => return Ok(false),
+            },
+            UiObject::Line(line) => match attr.name() {
+                "length" => line.set_length(self.float(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::Layout(layout) => match attr.name() {
+                "margin" => {
+                    let [top, right, bottom, left] = self.margin(attr)?;
+                    layout.set_margin(top, right, bottom, left);
+                }
+                "taborder" | "tab-order" => layout.set_taborder(self.orient(attr)?),
+                "background-color" => layout.set_background_color(Color::html(text.trim())),
+                "border-color" => layout.set_border_color(Color::html(text.trim())),
+                _ => return Ok(false),
+            },
+            UiObject::Window(window) => match attr.name() {
+                "title" => self.localized(attr, |text| window.set_title(text), |rid| window.set_title_rid(rid))?,
+                "client-size" | "size" => {
+                    let [width, height] = self.size(attr)?;
+                    window.set_client_size(width, height);
+                }
+                "origin" => {
+                    let [x, y] = self.size(attr)?;
+                    window.set_origin(x, y);
+                }
+                _ => return Ok(false),
+            },
+            UiObject::MenuItem(item) => match attr.name() {
+                "text" => self.localized(attr, |text| item.set_text(text), |rid| item.set_text_rid(rid))?,
+                "image" => item.set_image(&self.image(attr)?),
+                "state" => item.set_state(self.state(attr)?),
+                "enabled" => item.set_enabled(self.boolean(attr)?),
+                "visible" => item.set_visible(self.boolean(attr)?),
+                _ => return Ok(false),
+            },
+            UiObject::Menu(_) => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Adds the `<item>` children of a list-like control, in the order they are declared.
+    fn apply_items(&self, node: Node, object: UiObject) -> Result<(), NappguiError> {
+        let items = node
+            .children()
+            .filter(|child| child.is_element() && Kind::from_tag(child.tag_name().name()) == Some(Kind::Item));
+        for (index, item) in items.enumerate() {
+            let index = index as u32;
+            let text = match attr(item, &["text"]) {
+                Some(text) => self.text(&text)?,
+                None => self.resource_text(item.text().map(str::trim).unwrap_or(""), tag_pos(item))?,
+            };
+            let image = match attr(item, &["image"]) {
+                Some(image) => Some(self.image(&image)?),
+                None => None,
+            };
+            match object {
+                UiObject::ListBox(listbox) => listbox.add_element(&text, image.as_ref()),
+                UiObject::PopUp(popup) => popup.add_element(&text, image.as_ref()),
+                UiObject::Combo(combo) => combo.add_element(&text, image.as_ref()),
+                UiObject::Tabs(tabs) => tabs.add_element(&text, image.as_ref()),
+                _ => (),
+            }
+
+            for attr in item.attributes() {
+                match (attr.name(), object) {
+                    ("name" | "text" | "image", _) => (),
+                    ("selected", UiObject::ListBox(listbox)) => {
+                        if self.boolean(&attr)? {
+                            listbox.select(index, true);
+                        }
+                    }
+                    ("selected", UiObject::PopUp(popup)) => {
+                        if self.boolean(&attr)? {
+                            popup.set_selected(index);
+                        }
+                    }
+                    ("selected", UiObject::Combo(combo)) => {
+                        if self.boolean(&attr)? {
+                            combo.set_selected(index);
+                        }
+                    }
+                    ("selected", UiObject::Tabs(tabs)) => {
+                        if self.boolean(&attr)? {
+                            tabs.set_selected(index);
+                        }
+                    }
+                    ("color", UiObject::ListBox(listbox)) => listbox.set_color(index, Color::html(attr.value().trim())),
+                    ("checked" | "check", UiObject::ListBox(listbox)) => listbox.check(index, self.boolean(&attr)?),
+                    _ => return Err(self.unknown_attr(item, &attr)),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the `<column>` children of a table view, in the order they are declared.
+    fn apply_table_columns(&self, node: Node, mut table: TableView) -> Result<(), NappguiError> {
+        let columns = node
+            .children()
+            .filter(|child| child.is_element() && Kind::from_tag(child.tag_name().name()) == Some(Kind::Column));
+        for column in columns {
+            let column_id = table.add_column();
+            for attr in column.attributes() {
+                match attr.name() {
+                    "name" => (),
+                    "title" | "text" => table.set_header_title(column_id, &self.text(&attr)?),
+                    "width" => table.set_column_width(column_id, self.float(&attr)?),
+                    "limits" => {
+                        let [min, max] = self.size(&attr)?;
+                        table.set_column_limits(column_id, min, max);
+                    }
+                    "align" => table.set_column_align(column_id, self.align(&attr)?),
+                    "header-align" => table.set_header_align(column_id, self.align(&attr)?),
+                    "resizable" => table.set_column_resizable(column_id, self.boolean(&attr)?),
+                    _ => return Err(self.unknown_attr(column, &attr)),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the `<column>` and `<row>` children of a layout. Both need an `index` attribute.
+    ///
+    /// # Remarks
+    /// The columns (rows) with `expand="true"` share the extra space. The `weight` is the
+    /// proportion of space of each one, the last one takes the rest.
+    fn apply_layout_lines(&self, node: Node, layout: Layout) -> Result<(), NappguiError> {
+        for (kind, index_names, count) in [
+            (Kind::Column, &["index", "column", "col"][..], layout.ncols()),
+            (Kind::Row, &["index", "row"][..], layout.nrows()),
+        ] {
+            let lines = node
+                .children()
+                .filter(|child| child.is_element() && Kind::from_tag(child.tag_name().name()) == Some(kind));
+            let mut expand = Vec::new();
+            for line in lines {
+                let index = self.index(line, index_names, count, kind)?;
+                let (mut expanded, mut weight) = (false, None);
+                for attr in line.attributes() {
+                    match (attr.name(), kind) {
+                        ("name", _) => (),
+                        (name, _) if index_names.contains(&name) => (),
+                        ("expand", _) => expanded = self.boolean(&attr)?,
+                        ("weight", _) => weight = Some(self.float(&attr)?),
+                        ("width" | "size", Kind::Column) => layout.set_horizontal_size(index, self.float(&attr)?),
+                        ("margin", Kind::Column) => layout.set_horizontal_margin(index, self.float(&attr)?),
+                        ("visible", Kind::Column) => layout.set_column_visible(index, self.boolean(&attr)?),
+                        ("height" | "size", Kind::Row) => layout.set_vertical_size(index, self.float(&attr)?),
+                        ("margin", Kind::Row) => layout.set_vertical_margin(index, self.float(&attr)?),
+                        ("visible", Kind::Row) => layout.set_row_visible(index, self.boolean(&attr)?),
+                        _ => return Err(self.unknown_attr(line, &attr)),
+                    }
+                }
+                if expanded {
+                    expand.push((index, weight, line));
+                }
+            }
+            // The last expanded line takes the rest of the space, so a weight would be ignored.
+            if let Some((_, Some(_), line)) = expand.last() {
+                let what = if kind == Kind::Column { "column" } else { "row" };
+                let pos = line
+                    .attributes()
+                    .find(|attr| attr.name() == "weight")
+                    .map_or_else(|| tag_pos(*line), |attr| attr.range_value().start);
+                return Err(self.error(
+                    pos,
+                    format!(
+                        "the last expanded {} takes the rest of the space, it can't have a `weight`",
+                        what
+                    ),
+                ));
+            }
+            if let Some((_, first)) = expand.split_last() {
+                let default_weight = 1.0 / expand.len() as f32;
+                let indices = expand.iter().map(|(index, _, _)| *index).collect::<Vec<_>>();
+                let weights = first
+                    .iter()
+                    .map(|(_, weight, _)| weight.unwrap_or(default_weight))
+                    .collect::<Vec<_>>();
+                match kind {
+                    Kind::Column => layout.set_horizontal_expand(&indices, &weights),
+                    _ => layout.set_vertical_expand(&indices, &weights),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the attributes of a `<cell>` to the cell of its layout.
+    fn apply_cell(&self, node: Node, layout: Layout) -> Result<(), NappguiError> {
+        let (col, row) = self.cell_index(node, layout)?;
+        for attr in node.attributes() {
+            match attr.name() {
+                "name" | "for" | "control" | "column" | "col" | "row" => (),
+                "halign" | "horizontal-align" => layout.set_horizontal_align(col, row, self.align(&attr)?),
+                "valign" | "vertical-align" => layout.set_vertical_align(col, row, self.align(&attr)?),
+                "tabstop" => layout.set_tabstop(col, row, self.boolean(&attr)?),
+                "enabled" => layout.set_enabled(col, row, self.boolean(&attr)?),
+                "visible" => layout.set_visible(col, row, self.boolean(&attr)?),
+                "padding" => {
+                    let [top, right, bottom, left] = self.margin(&attr)?;
+                    layout.set_padding(col, row, top, right, bottom, left);
+                }
+                _ => return Err(self.unknown_attr(node, &attr)),
+            }
+        }
+        Ok(())
+    }
+
+    /// The column and the row of a `<cell>`, which must be in the range of its layout.
+    fn cell_index(&self, node: Node, layout: Layout) -> Result<(u32, u32), NappguiError> {
+        let col = self.index(node, &["column", "col"], layout.ncols(), Kind::Column)?;
+        let row = self.index(node, &["row"], layout.nrows(), Kind::Row)?;
+        Ok((col, row))
+    }
+
+    /// The index of a column or a row of a layout, which must be less than count.
+    fn index(&self, node: Node, names: &[&str], count: u32, kind: Kind) -> Result<u32, NappguiError> {
+        let attr = self.required(node, names)?;
+        let index = self.uint(&attr)?;
+        if index >= count {
+            let lines = if kind == Kind::Column { "column(s)" } else { "row(s)" };
+            return Err(self.error(
+                attr.range_value().start,
+                format!("index {} is out of range, the layout has {} {}", index, count, lines),
+            ));
+        }
+        Ok(index)
+    }
+
+    /// The font from the `font-family`, `font-size` and `font-style` attributes. The font is only
+    /// created if `font-size` is declared.
+    fn font(&self, node: Node) -> Result<Option<Font>, NappguiError> {
+        let Some(size) = attr(node, &["font-size"]) else {
+            return Ok(None);
+        };
+        let size = self.float(&size)?;
+        let style = match attr(node, &["font-style"]) {
+            Some(style) => self.font_style(&style)?,
+            None => FontStyle::Normal,
+        };
+        let font = match attr(node, &["font-family"]) {
+            Some(family) => Font::new(family.value(), size, style),
+            None => Font::system(size, style),
+        };
+        Ok(Some(font))
+    }
+
+    /// Sets a text of an attribute with `set_text`, or with `set_rid` if it is a resource, so it
+    /// is set again when the language changes.
+    fn localized(
+        &self,
+        attr: &Attribute,
+        set_text: impl FnOnce(&str),
+        set_rid: impl FnOnce(&str),
+    ) -> Result<(), NappguiError> {
+        match self.resource(attr.value(), attr.range_value().start)? {
+            Some(rid) => set_rid(rid),
+            None => set_text(literal_text(attr.value())),
+        }
+        Ok(())
+    }
+
+    /// The text of an attribute. `@HELLO_MSG` is a text of the resources, `@@` is a literal `@`.
+    fn text(&self, attr: &Attribute) -> Result<String, NappguiError> {
+        self.resource_text(attr.value(), attr.range_value().start)
+    }
+
+    /// A text at a position of the XML, which can be a text of the resources. See text.
+    fn resource_text(&self, value: &str, pos: usize) -> Result<String, NappguiError> {
+        Ok(match self.resource(value, pos)? {
+            Some(rid) => gui_text(rid),
+            None => literal_text(value).to_string(),
+        })
+    }
+
+    /// The resource ID of a value like `@HELLO_MSG`, or None if the value is not a resource.
+    fn resource(&self, value: &str, pos: usize) -> Result<Option<&'a str>, NappguiError> {
+        let Some(name) = value.strip_prefix('@').filter(|name| !name.starts_with('@')) else {
+            return Ok(None);
+        };
+        let name = name.trim();
+        self.resources
+            .iter()
+            .find(|(resource, _)| *resource == name)
+            .map(|(_, rid)| Some(*rid))
+            .ok_or_else(|| self.error(pos, format!("unknown resource `{}`", name)))
+    }
+
+    /// Loads the image of an attribute. `@ICON_PNG` is an image of the resources. Relative paths
+    /// are resolved from the directory of the XML file, if it was loaded from a file.
+    fn image(&self, attr: &Attribute) -> Result<Image, NappguiError> {
+        if let Some(rid) = self.resource(attr.value(), attr.range_value().start)? {
+            return Ok(gui_image(rid).clone());
+        }
+        let path = Path::new(literal_text(attr.value()).trim());
+        let path = match self.dir {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path.to_path_buf(),
+        };
+        path.to_str().and_then(Image::from_file).ok_or_else(|| {
+            self.error(
+                attr.range_value().start,
+                format!("unable to load the image `{}`", path.display()),
+            )
+        })
+    }
+
+    /// An optional boolean option of a constructor.
+    fn flag(&self, node: Node, names: &[&str]) -> Result<bool, NappguiError> {
+        match attr(node, names) {
+            Some(attr) => self.boolean(&attr),
+            None => Ok(false),
+        }
+    }
+
+    /// An optional attribute with one of the given options.
+    fn choice(
+        &self,
+        node: Node,
+        names: &[&str],
+        options: &[&'static str],
+    ) -> Result<Option<&'static str>, NappguiError> {
+        let Some(attr) = attr(node, names) else {
+            return Ok(None);
+        };
+        let value = attr.value().trim().to_lowercase();
+        match options.iter().find(|option| **option == value) {
+            Some(option) => Ok(Some(option)),
+            None => {
+                let options = options.iter().map(|option| format!("`{}`", option)).collect::<Vec<_>>();
+                Err(self.invalid_value(&attr, &format!("one of {}", options.join(", "))))
+            }
+        }
+    }
+
+    fn required(&self, node: Node<'a, 'input>, names: &[&str]) -> Result<Attribute<'a, 'input>, NappguiError> {
+        attr(node, names).ok_or_else(|| {
+            self.error(
+                tag_pos(node),
+                format!("missing attribute `{}` in `<{}>`", names[0], node.tag_name().name()),
+            )
+        })
+    }
+
+    fn parse<T>(
+        &self,
+        attr: &Attribute,
+        expected: &str,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, NappguiError> {
+        parse(attr.value().trim()).ok_or_else(|| self.invalid_value(attr, expected))
+    }
+
+    fn float(&self, attr: &Attribute) -> Result<f32, NappguiError> {
+        self.parse(attr, "a number", |value| value.parse().ok())
+    }
+
+    fn uint(&self, attr: &Attribute) -> Result<u32, NappguiError> {
+        self.parse(attr, "a positive integer", |value| value.parse().ok())
+    }
+
+    fn boolean(&self, attr: &Attribute) -> Result<bool, NappguiError> {
+        self.parse(attr, "`true` or `false`", parse_bool)
+    }
+
+    fn bools(&self, attr: &Attribute) -> Result<[bool; 2], NappguiError> {
+        self.parse(attr, "two booleans, like `true,false`", |value| {
+            parse_list(value, parse_bool)?.try_into().ok()
+        })
+    }
+
+    fn size(&self, attr: &Attribute) -> Result<[f32; 2], NappguiError> {
+        self.pair(attr, "two numbers, like `200,100`")
+    }
+
+    /// Two numbers separated by a comma.
+    fn pair<T: std::str::FromStr>(&self, attr: &Attribute, expected: &str) -> Result<[T; 2], NappguiError> {
+        self.parse(attr, expected, |value| {
+            parse_list(value, |value| value.parse().ok())?.try_into().ok()
+        })
+    }
+
+    /// One number for all sides, or four for top, right, bottom and left.
+    fn margin(&self, attr: &Attribute) -> Result<[f32; 4], NappguiError> {
+        let expected = "one number, or four numbers for top, right, bottom and left";
+        self.parse(attr, expected, |value| {
+            match parse_list(value, |value| value.parse::<f32>().ok())?.as_slice() {
+                [all] => Some([*all; 4]),
+                [top, right, bottom, left] => Some([*top, *right, *bottom, *left]),
+                _ => None,
+            }
+        })
+    }
+
+    fn align(&self, attr: &Attribute) -> Result<Align, NappguiError> {
+        let variants = [
+            ("left", Align::Left),
+            ("center", Align::Center),
+            ("right", Align::Right),
+            ("justify", Align::Justify),
+        ];
+        self.parse(attr, "`left`, `center`, `right` or `justify`", |value| {
+            variant(value, &variants)
+        })
+    }
+
+    fn font_style(&self, attr: &Attribute) -> Result<FontStyle, NappguiError> {
+        // FontStyle is not Copy, the styles are combined as bits.
+        let variants = [
+            ("normal", FontStyle::Normal.bits()),
+            ("bold", FontStyle::Bold.bits()),
+            ("italic", FontStyle::Italic.bits()),
+            ("strike_out|strike-out", FontStyle::StrikeOut.bits()),
+            ("underlined", FontStyle::Underlined.bits()),
+            ("subscript", FontStyle::Subscript.bits()),
+            ("superscript", FontStyle::Superscript.bits()),
+            ("points", FontStyle::Points.bits()),
+            ("cell", FontStyle::Cell.bits()),
+        ];
+        self.parse(attr, "font styles separated by `|`, like `bold|italic`", |value| {
+            let bits = value
+                .split('|')
+                .try_fold(0, |bits, style| Some(bits | variant(style, &variants)?))?;
+            Some(FontStyle::from_bits_retain(bits))
+        })
+    }
+
+    fn state(&self, attr: &Attribute) -> Result<GuiState, NappguiError> {
+        let variants = [("on", GuiState::On), ("off", GuiState::Off), ("mixed", GuiState::Mixed)];
+        self.parse(attr, "`on`, `off` or `mixed`", |value| variant(value, &variants))
+    }
+
+    fn scale(&self, attr: &Attribute) -> Result<Scale, NappguiError> {
+        let variants = [
+            ("none", Scale::None),
+            ("auto", Scale::Auto),
+            ("aspect", Scale::Aspect),
+            ("aspectdw", Scale::AspectDW),
+            ("adjust", Scale::Adjust),
+        ];
+        self.parse(attr, "`none`, `auto`, `aspect`, `aspectdw` or `adjust`", |value| {
+            variant(value, &variants)
+        })
+    }
+
+    fn position(&self, attr: &Attribute) -> Result<GuiPosition, NappguiError> {
+        let variants = [
+            ("none", GuiPosition::None),
+            ("left", GuiPosition::Left),
+            ("top", GuiPosition::Top),
+            ("right", GuiPosition::Right),
+            ("bottom", GuiPosition::Bottom),
+        ];
+        self.parse(attr, "`none`, `left`, `top`, `right` or `bottom`", |value| {
+            variant(value, &variants)
+        })
+    }
+
+    fn orient(&self, attr: &Attribute) -> Result<GuiOrient, NappguiError> {
+        let variants = [("horizontal", GuiOrient::Horizontal), ("vertical", GuiOrient::Vertical)];
+        self.parse(attr, "`horizontal` or `vertical`", |value| variant(value, &variants))
+    }
+
+    /// Builds an error at a position of the XML.
+    fn error(&self, pos: usize, message: String) -> NappguiError {
+        let pos = self.doc.text_pos_at(pos);
+        NappguiError::Xml {
+            message,
+            row: pos.row,
+            col: pos.col,
+        }
+    }
+
+    fn unknown_attr(&self, node: Node, attr: &Attribute) -> NappguiError {
+        self.error(
+            attr.range_qname().start,
+            format!("unknown attribute `{}` for `<{}>`", attr.name(), node.tag_name().name()),
+        )
+    }
+
+    fn invalid_value(&self, attr: &Attribute, expected: &str) -> NappguiError {
+        self.error(
+            attr.range_value().start,
+            format!(
+                "invalid value `{}` for `{}`, expected {}",
+                attr.value(),
+                attr.name(),
+                expected
+            ),
+        )
+    }
+}
+
+/// A text that is not a resource. `@@` is an escaped `@`.
+fn literal_text(value: &str) -> &str {
+    value.strip_prefix('@').unwrap_or(value)
+}
+
+/// Returns the first attribute found. Alternative names of the attribute are given in order.
+fn attr<'a, 'input>(node: Node<'a, 'input>, names: &[&str]) -> Option<Attribute<'a, 'input>> {
+    names
+        .iter()
+        .find_map(|name| node.attributes().find(|attr| attr.name() == *name))
+}
+
+/// Position of the tag name of an element, after the `<`.
+fn tag_pos(node: Node) -> usize {
+    node.range().start + 1
+}
+
+/// Checks if an element can be declared in another one.
+fn placement_valid(kind: Kind, custom: bool, parent: Kind) -> bool {
+    if custom {
+        return true;
+    }
+    match kind {
+        Kind::Cell | Kind::Row => parent == Kind::Layout,
+        Kind::Column => matches!(parent, Kind::Layout | Kind::TableView),
+        Kind::Item => matches!(parent, Kind::ListBox | Kind::PopUp | Kind::Combo | Kind::Tabs),
+        Kind::Layout => matches!(parent, Kind::Panel | Kind::Layout),
+        Kind::Menu => matches!(parent, Kind::Window | Kind::MenuItem),
+        Kind::MenuItem => parent == Kind::Menu,
+        _ => true,
+    }
+}
+
+/// Places an object in a cell of a layout. Returns false if the object can't be placed in a layout.
+fn set_control(layout: Layout, col: u32, row: u32, object: UiObject) -> bool {
+    match object {
+        UiObject::Button(button) => layout.set_control(col, row, button),
+        UiObject::Combo(combo) => layout.set_control(col, row, combo),
+        UiObject::Edit(edit) => layout.set_control(col, row, edit),
+        UiObject::ImageView(view) => layout.set_control(col, row, view),
+        UiObject::Label(label) => layout.set_control(col, row, label),
+        UiObject::Panel(panel) => layout.set_control(col, row, panel),
+        UiObject::ListBox(listbox) => layout.set_control(col, row, listbox),
+        UiObject::PopUp(popup) => layout.set_control(col, row, popup),
+        UiObject::Progress(progress) => layout.set_control(col, row, progress),
+        UiObject::Slider(slider) => layout.set_control(col, row, slider),
+        UiObject::SplitView(split) => layout.set_control(col, row, split),
+        UiObject::TableView(table) => layout.set_control(col, row, table),
+        UiObject::Tabs(tabs) => layout.set_control(col, row, tabs),
+        UiObject::TextView(text) => layout.set_control(col, row, text),
+        UiObject::UpDown(updown) => layout.set_control(col, row, updown),
+        UiObject::View(view) => layout.set_control(col, row, view),
+        UiObject::WebView(view) => layout.set_control(col, row, view),
+        UiObject::Layout(sublayout) => layout.set_control(col, row, sublayout),
+        UiObject::Line(_) | UiObject::Window(_) | UiObject::Menu(_) | UiObject::MenuItem(_) => return false,
+    }
+    true
+}
+
+/// Find a value from its name in XML. Alternative names are separated by `|`.
+fn variant<T: Copy>(value: &str, variants: &[(&str, T)]) -> Option<T> {
+    let value = value.trim().to_lowercase();
+    variants
+        .iter()
+        .find(|(names, _)| names.split('|').any(|name| name == value))
+        .map(|(_, variant)| *variant)
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "1" => Some(true),
+        "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parse a list of values separated by commas.
+fn parse_list<T>(value: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Vec<T>> {
+    value.split(',').map(|value| parse(value.trim())).collect()
+}
@@ -14,6 +14,7 @@ use std::rc::Rc;
 
 use crate::draw_2d::{Color, Image, Point2D, Rect2D, Size2D};
 use crate::gui::event::{PositionEvent, SizeEvent, WindowCloseEvent};
+use crate::gui::locale::{forget_localized_text, set_localized_text, Localized};
 use crate::gui::{define_object, listener, AsObject, Button, Callback, Control, FocusInfo, Menu, Panel};
 use crate::types::{Align, GuiCursor, GuiFocus, GuiTab, KeyCode, ModifierKey, WindowFlags};
 
@@ -130,10 +131,19 @@ impl Window {
 
     /// Set the text that will display the window in the title bar.
     pub fn set_title(&self, text: &str) {
+        forget_localized_text(Localized::WindowTitle(*self));
         let text = CString::new(text).unwrap();
         unsafe { window_title(self.as_ptr(), text.as_ptr()) }
     }
 
+    /// Set the title of the window from a resource.
+    ///
+    /// # Remarks
+    /// The title is set again when the language changes with gui_language.
+    pub fn set_title_rid(&self, rid: &str) {
+        set_localized_text(Localized::WindowTitle(*self), rid);
+    }
+
     /// Show the window. By default windows are created hidden. You have to show them explicitly.
     pub fn show(&self) {
         unsafe { window_show(self.as_ptr()) }
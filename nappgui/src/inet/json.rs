@@ -0,0 +1,89 @@
+use nappgui_sys::{
+    array_create, array_destroy_ptr, array_get, array_size, json_read_imp, json_write_imp, str_destroy, tc, Array,
+    JsonOpts,
+};
+
+use crate::{
+    core::{dbind::dbind_take, DataBind, Stream},
+    error::NappguiError,
+};
+
+/// Error log of the JSON parser. NAppGUI adds a message for each error found.
+struct JsonLog {
+    inner: *mut Array,
+}
+
+impl JsonLog {
+    /// Type name of the elements of the log.
+    const TYPE: &'static std::ffi::CStr = c"String";
+
+    fn new() -> Self {
+        let inner = unsafe { array_create(size_of::<*mut nappgui_sys::String>() as _, Self::TYPE.as_ptr()) };
+        Self { inner }
+    }
+
+    fn opts(&self) -> JsonOpts {
+        JsonOpts { log: self.inner as _ }
+    }
+
+    fn messages(&self) -> Vec<String> {
+        let size = unsafe { array_size(self.inner) };
+        (0..size)
+            .map(|i| {
+                let text = unsafe { tc(*(array_get(self.inner, i) as *const *const nappgui_sys::String)) };
+                unsafe { std::ffi::CStr::from_ptr(text) }.to_string_lossy().into_owned()
+            })
+            .collect()
+    }
+
+    /// Builds the error with the messages of the log and the position where the parser stopped.
+    fn error(&self, stream: &Stream) -> NappguiError {
+        let mut message = self.messages().join("\n");
+        if message.is_empty() {
+            message = "Invalid JSON data".to_string();
+        }
+        NappguiError::Json {
+            message,
+            row: stream.row(),
+            col: stream.col(),
+        }
+    }
+}
+
+impl Drop for JsonLog {
+    fn drop(&mut self) {
+        let size = unsafe { array_size(self.inner) };
+        for i in 0..size {
+            unsafe { str_destroy(array_get(self.inner, i) as *mut *mut nappgui_sys::String) };
+        }
+        unsafe { array_destroy_ptr(&mut self.inner, None, Self::TYPE.as_ptr()) };
+    }
+}
+
+/// Read a DBind object from a JSON stream.
+pub(crate) fn json_read<T: DataBind>(stream: &mut Stream) -> Result<T, NappguiError> {
+    T::dbind_register_type()?;
+    let log = JsonLog::new();
+    let opts = log.opts();
+    let obj = unsafe { json_read_imp(stream.inner, &opts, T::TYPE.as_ptr()) };
+    if obj.is_null() || !log.messages().is_empty() {
+        if !obj.is_null() {
+            let mut obj = obj as *mut u8;
+            unsafe { nappgui_sys::dbind_destroy_imp(&mut obj, T::TYPE.as_ptr()) };
+        }
+        return Err(log.error(stream));
+    }
+    Ok(unsafe { dbind_take(obj as _) })
+}
+
+/// Write a DBind object to a stream in JSON format.
+pub(crate) fn json_write<T: DataBind>(stream: &mut Stream, obj: &T) -> Result<(), NappguiError> {
+    T::dbind_register_type()?;
+    let log = JsonLog::new();
+    let opts = log.opts();
+    unsafe { json_write_imp(stream.inner, obj as *const T as _, &opts, T::TYPE.as_ptr()) };
+    if !log.messages().is_empty() {
+        return Err(log.error(stream));
+    }
+    stream.check(())
+}
@@ -1,4 +1,6 @@
 /// Wrapper to HTTP library. See https://nappgui.com/en/inet/httpreq.html
 mod http;
+/// Import/export of DataBind types in JSON format. See https://nappgui.com/en/inet/json.html
+pub(crate) mod json;
 
 pub use http::*;
@@ -9,6 +9,9 @@ pub mod core;
 /// graphics. It depends directly on Geom2D and, as we will see later, drawing does not
 /// imply having a graphical user interface in the program.
 pub mod draw_2d;
+/// The Geom2D library allows working with geometric elements in the plane: points, segments,
+/// circles, boxes, triangles and polygons, in both single and double precision.
+pub mod geom_2d;
 /// The Gui library allows you to create graphical user interfaces in a simple and intuitive way.
 /// Only available for desktop applications for obvious reasons, unlike the rest of libraries
 /// that can also be used in command line applications.
@@ -28,11 +31,13 @@ pub mod prelude {
     pub use crate::core::*;
     pub use crate::draw_2d::*;
     pub use crate::error::*;
+    pub use crate::geom_2d::*;
     pub use crate::gui::*;
     pub use crate::gui::event::*;
     pub use crate::types::*;
     pub use nappgui_macros::include_resource;
     pub use nappgui_macros::ui as nappgui_ui;
+    pub use nappgui_macros::DataBind;
 }
 
 /// Embed resources
@@ -483,6 +483,180 @@ define_enum! {
         Bottom = nappgui_sys::_gui_pos_t_ekGUI_POS_BOTTOM
     }
 
+    /// Drawing operations.
+    pub enum DrawOp {
+        /// Draw the contour of the figure, using the current line style.
+        Stroke = nappgui_sys::_drawop_t_ekSTROKE,
+        /// Fill the figure, using the current fill style.
+        Fill = nappgui_sys::_drawop_t_ekFILL,
+        /// Draw the contour first and then fill the figure.
+        StrokeFill = nappgui_sys::_drawop_t_ekSKFILL,
+        /// Fill the figure first and then draw the contour.
+        FillStroke = nappgui_sys::_drawop_t_ekFILLSK,
+    }
+
+    /// Style of line ends.
+    pub enum LineCap {
+        /// Flat end at the last point of the line.
+        Flat = nappgui_sys::_linecap_t_ekLCFLAT,
+        /// Square end, extended half the line width beyond the last point.
+        Square = nappgui_sys::_linecap_t_ekLCSQUARE,
+        /// Rounded end, centered on the last point.
+        Round = nappgui_sys::_linecap_t_ekLCROUND,
+    }
+
+    /// Style of the junctions between line segments.
+    pub enum LineJoin {
+        /// Junction in peak.
+        Miter = nappgui_sys::_linejoin_t_ekLJMITER,
+        /// Rounded junction.
+        Round = nappgui_sys::_linejoin_t_ekLJROUND,
+        /// Beveled junction.
+        Bevel = nappgui_sys::_linejoin_t_ekLJBEVEL,
+    }
+
+    /// Behavior of the fill pattern outside the gradient limits.
+    pub enum FillWrap {
+        /// The last value is used as fill color.
+        Clamp = nappgui_sys::_fillwrap_t_ekFCLAMP,
+        /// The pattern is repeated.
+        Tile = nappgui_sys::_fillwrap_t_ekFTILE,
+        /// The pattern is repeated, reversing the order.
+        Flip = nappgui_sys::_fillwrap_t_ekFFLIP,
+    }
+
+    /// Indicators of the table headers.
+    pub enum Indicator {
+        /// No indicator.
+        None = nappgui_sys::_indicator_t_ekINDNONE,
+        /// Up arrow, ascending order.
+        UpArrow = nappgui_sys::_indicator_t_ekINDUP_ARROW,
+        /// Down arrow, descending order.
+        DownArrow = nappgui_sys::_indicator_t_ekINDDOWN_ARROW,
+    }
+
+    /// Byte order of binary data in streams.
+    pub enum Endian {
+        /// Little endian. Least significant byte first.
+        Little = nappgui_sys::_endian_t_ekLITEND,
+        /// Big endian. Most significant byte first.
+        Big = nappgui_sys::_endian_t_ekBIGEND,
+    }
+
+    /// Unicode encoding of text data in streams.
+    pub enum Unicode {
+        /// UTF-8 encoding.
+        Utf8 = nappgui_sys::_unicode_t_ekUTF8,
+        /// UTF-16 encoding.
+        Utf16 = nappgui_sys::_unicode_t_ekUTF16,
+        /// UTF-32 encoding.
+        Utf32 = nappgui_sys::_unicode_t_ekUTF32,
+    }
+
+    /// State of a stream.
+    pub enum StreamState {
+        /// All is correct.
+        Ok = nappgui_sys::_sstate_t_ekSTOK,
+        /// The end of the stream has been reached.
+        End = nappgui_sys::_sstate_t_ekSTEND,
+        /// The stream data is corrupt.
+        Corrupt = nappgui_sys::_sstate_t_ekSTCORRUPT,
+        /// The stream is broken, due to an i/o or network error.
+        Broken = nappgui_sys::_sstate_t_ekSTBROKEN,
+    }
+
+    /// Lexical tokens read from a stream.
+    pub enum Token {
+        /// Single-line comment `//`.
+        SingleLineComment = nappgui_sys::_token_t_ekTSLCOM,
+        /// Multi-line comment `/* */`.
+        MultiLineComment = nappgui_sys::_token_t_ekTMLCOM,
+        /// Blank spaces.
+        Space = nappgui_sys::_token_t_ekTSPACE,
+        /// New line `\n`.
+        Eol = nappgui_sys::_token_t_ekTEOL,
+        /// Less sign `<`.
+        Less = nappgui_sys::_token_t_ekTLESS,
+        /// Greater sign `>`.
+        Greater = nappgui_sys::_token_t_ekTGREAT,
+        /// Comma `,`.
+        Comma = nappgui_sys::_token_t_ekTCOMMA,
+        /// Period `.`.
+        Period = nappgui_sys::_token_t_ekTPERIOD,
+        /// Semicolon `;`.
+        Semicolon = nappgui_sys::_token_t_ekTSCOLON,
+        /// Colon `:`.
+        Colon = nappgui_sys::_token_t_ekTCOLON,
+        /// Open parenthesis `(`.
+        OpenParen = nappgui_sys::_token_t_ekTOPENPAR,
+        /// Close parenthesis `)`.
+        CloseParen = nappgui_sys::_token_t_ekTCLOSPAR,
+        /// Open bracket `[`.
+        OpenBracket = nappgui_sys::_token_t_ekTOPENBRAC,
+        /// Close bracket `]`.
+        CloseBracket = nappgui_sys::_token_t_ekTCLOSBRAC,
+        /// Open curly bracket `{`.
+        OpenCurly = nappgui_sys::_token_t_ekTOPENCURL,
+        /// Close curly bracket `}`.
+        CloseCurly = nappgui_sys::_token_t_ekTCLOSCURL,
+        /// Plus sign `+`.
+        Plus = nappgui_sys::_token_t_ekTPLUS,
+        /// Minus sign `-`.
+        Minus = nappgui_sys::_token_t_ekTMINUS,
+        /// Asterisk `*`.
+        Asterisk = nappgui_sys::_token_t_ekTASTERK,
+        /// Equals sign `=`.
+        Equals = nappgui_sys::_token_t_ekTEQUALS,
+        /// Dollar sign `$`.
+        Dollar = nappgui_sys::_token_t_ekTDOLLAR,
+        /// Percent sign `%`.
+        Percent = nappgui_sys::_token_t_ekTPERCENT,
+        /// Pound sign `#`.
+        Pound = nappgui_sys::_token_t_ekTPOUND,
+        /// Ampersand `&`.
+        Ampersand = nappgui_sys::_token_t_ekTAMPER,
+        /// Apostrophe `'`.
+        Apostrophe = nappgui_sys::_token_t_ekTAPOST,
+        /// Double quote `"`.
+        Quote = nappgui_sys::_token_t_ekTQUOTE,
+        /// Circumflex `^`.
+        Circumflex = nappgui_sys::_token_t_ekTCIRCUM,
+        /// Tilde `~`.
+        Tilde = nappgui_sys::_token_t_ekTTILDE,
+        /// Exclamation mark `!`.
+        Exclamation = nappgui_sys::_token_t_ekTEXCLA,
+        /// Question mark `?`.
+        Question = nappgui_sys::_token_t_ekTQUEST,
+        /// Vertical line `|`.
+        VerticalLine = nappgui_sys::_token_t_ekTVLINE,
+        /// Slash `/`.
+        Slash = nappgui_sys::_token_t_ekTSLASH,
+        /// Backslash `\`.
+        Backslash = nappgui_sys::_token_t_ekTBSLASH,
+        /// At sign `@`.
+        At = nappgui_sys::_token_t_ekTAT,
+        /// Integer number.
+        Integer = nappgui_sys::_token_t_ekTINTEGER,
+        /// Octal number.
+        Octal = nappgui_sys::_token_t_ekTOCTAL,
+        /// Hexadecimal number.
+        Hex = nappgui_sys::_token_t_ekTHEX,
+        /// Real number.
+        Real = nappgui_sys::_token_t_ekTREAL,
+        /// Text string between quotes.
+        String = nappgui_sys::_token_t_ekTSTRING,
+        /// Identifier (variable or function name).
+        Ident = nappgui_sys::_token_t_ekTIDENT,
+        /// Undefined token.
+        Undefined = nappgui_sys::_token_t_ekTUNDEF,
+        /// The stream data is corrupt.
+        Corrupt = nappgui_sys::_token_t_ekTCORRUP,
+        /// End of file.
+        Eof = nappgui_sys::_token_t_ekTEOF,
+        /// Start of user defined tokens.
+        Reserved = nappgui_sys::_token_t_ekTRESERVED,
+    }
+
     /// Cookie Policy.
     pub enum CookiePolicy {
         /// Disables cookies, that is, it does not send cookies in the request or store possible cookies in the response.